@@ -13,7 +13,9 @@ fn main() {
     bme280.init(&mut delay).unwrap();
     loop {
         let measurements = bme280.measure(&mut delay).unwrap();
-        println!("Relative Humidity = {}%", measurements.humidity);
+        if let Some(humidity) = measurements.humidity {
+            println!("Relative Humidity = {}%", humidity);
+        }
         println!("Temperature = {} deg C", measurements.temperature);
         println!("Pressure = {} pascals", measurements.pressure);
         thread::sleep(Duration::from_secs(1));
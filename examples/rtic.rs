@@ -82,7 +82,9 @@ mod app {
         loop {
             match bme.measure(delay) {
                 Ok(measurements) => {
-                    defmt::println!("Relative Humidity = {}%", measurements.humidity);
+                    if let Some(humidity) = measurements.humidity {
+                        defmt::println!("Relative Humidity = {}%", humidity);
+                    }
                     defmt::println!("Temperature = {} deg C", measurements.temperature);
                     defmt::println!("Pressure = {} pascals", measurements.pressure);
                 }
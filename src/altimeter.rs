@@ -0,0 +1,65 @@
+//! Altitude above sea level or above a reference point.
+//!
+//! ```
+//! use bme280::altimeter::Altimeter;
+//! use bme280::derived::SEA_LEVEL_PRESSURE;
+//!
+//! let mut altimeter = Altimeter::new(SEA_LEVEL_PRESSURE);
+//! altimeter.tare(100000.0);
+//! assert_eq!(altimeter.altitude(100000.0), 0.0);
+//! assert!(altimeter.altitude(99900.0) > 8.0);
+//! ```
+
+use super::derived;
+
+/// Altitude from the pressure, above sea level or, once tared, above the point of the tare
+#[derive(Debug, Copy, Clone)]
+pub struct Altimeter {
+    sea_level_pressure: f32,
+    tare: Option<f32>,
+}
+
+impl Altimeter {
+    /// Create a new altimeter, given the current pressure at sea level in pascals, e.g.
+    /// [`derived::SEA_LEVEL_PRESSURE`]
+    pub fn new(sea_level_pressure: f32) -> Self {
+        Altimeter {
+            sea_level_pressure,
+            tare: None,
+        }
+    }
+
+    /// Set the pressure at sea level in pascals
+    pub fn set_sea_level_pressure(&mut self, sea_level_pressure: f32) {
+        self.sea_level_pressure = sea_level_pressure;
+    }
+
+    /// Take the current `pressure` in pascals as the zero of the altitude, e.g. at launch or on
+    /// the ground floor
+    pub fn tare(&mut self, pressure: f32) {
+        self.tare = Some(pressure);
+    }
+
+    /// Measure the altitude above sea level again
+    pub fn clear_tare(&mut self) {
+        self.tare = None;
+    }
+
+    /// Altitude in meters at the `pressure` in pascals, relative to the tare if any
+    pub fn altitude(&self, pressure: f32) -> f32 {
+        let altitude = derived::altitude(pressure, self.sea_level_pressure);
+        match self.tare {
+            Some(tare) => altitude - derived::altitude(tare, self.sea_level_pressure),
+            None => altitude,
+        }
+    }
+
+    /// Altitude in meters at the `pressure` in pascals after the hypsometric equation, given the
+    /// mean `temperature` of the air in degrees celsius, relative to the tare if any. This is
+    /// more accurate than [`Altimeter::altitude`] when the temperature is far from the standard
+    /// atmosphere, and is best suited to small height changes from a tare.
+    pub fn hypsometric_altitude(&self, pressure: f32, temperature: f32) -> f32 {
+        let reference = self.tare.unwrap_or(self.sea_level_pressure);
+        derived::hypsometric_altitude(pressure, reference, temperature)
+    }
+}
@@ -0,0 +1,131 @@
+//! Time-weighted averages of irregularly sampled measurements.
+//!
+//! Each measurement is weighted by the time until the next one, so that bursts of samples do not
+//! bias the average, as required for exposure limits over e.g. eight-hour shifts.
+
+use super::Measurements;
+
+/// Eight hours, in seconds
+pub const EIGHT_HOURS: u32 = 8 * 60 * 60;
+
+/// Accumulator of the time-weighted average of the measurements over consecutive windows
+///
+/// The value of a measurement holds until the next one. Gaps longer than a window count as a
+/// single window of the last value.
+#[derive(Debug, Copy, Clone)]
+pub struct TimeWeightedAverage {
+    window: u32,
+    elapsed: u32,
+    sum: [f64; 3],
+    last: Option<(u32, [f32; 3])>,
+}
+
+impl TimeWeightedAverage {
+    /// Create a new accumulator for windows of `window` seconds, e.g. [`EIGHT_HOURS`]
+    pub fn new(window: u32) -> Self {
+        TimeWeightedAverage {
+            window,
+            elapsed: 0,
+            sum: [0.0; 3],
+            last: None,
+        }
+    }
+
+    /// Feed the `measurements` taken at `timestamp`, in seconds from an arbitrary origin and
+    /// allowed to wrap around, and return the average over the window completed by them, if any
+    pub fn update<E>(
+        &mut self,
+        measurements: &Measurements<E>,
+        timestamp: u32,
+    ) -> Option<Measurements<E>> {
+        let channels = [
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity.unwrap_or(f32::NAN),
+        ];
+        let mut average = None;
+        if let Some((last_timestamp, last)) = self.last {
+            let mut dt = timestamp.wrapping_sub(last_timestamp);
+            let remaining = self.window - self.elapsed;
+            if dt >= remaining {
+                self.add(last, remaining);
+                average = self.average();
+                self.elapsed = 0;
+                self.sum = [0.0; 3];
+                dt -= remaining;
+            }
+            self.add(last, dt.min(self.window));
+        }
+        self.last = Some((timestamp, channels));
+        average
+    }
+
+    /// Average over the current, incomplete window, `None` until time has elapsed in it
+    pub fn average<E>(&self) -> Option<Measurements<E>> {
+        if self.elapsed == 0 {
+            return None;
+        }
+        let elapsed = self.elapsed as f64;
+        Some(Measurements::from_channels([
+            (self.sum[0] / elapsed) as f32,
+            (self.sum[1] / elapsed) as f32,
+            (self.sum[2] / elapsed) as f32,
+        ]))
+    }
+
+    /// Forget the measurements and start a new window
+    pub fn reset(&mut self) {
+        *self = TimeWeightedAverage::new(self.window);
+    }
+
+    fn add(&mut self, channels: [f32; 3], duration: u32) {
+        for (sum, value) in self.sum.iter_mut().zip(channels) {
+            *sum += value as f64 * duration as f64;
+        }
+        self.elapsed += duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurements(temperature: f32) -> Measurements<()> {
+        Measurements::from_channels([temperature, 100_000.0, f32::NAN])
+    }
+
+    #[test]
+    fn weights_by_duration_and_rolls_over() {
+        let mut average = TimeWeightedAverage::new(100);
+        assert!(average.update(&measurements(10.0), 0).is_none());
+        assert!(average.update(&measurements(20.0), 25).is_none());
+        // 10 °C for 25 s and 20 °C for 75 s complete the window
+        let window = average.update(&measurements(30.0), 110).unwrap();
+        assert_eq!(window.temperature, 17.5);
+        assert_eq!(window.pressure, 100_000.0);
+        assert_eq!(window.humidity, None);
+        // the remaining 10 s of 20 °C start the next window
+        assert_eq!(average.average::<()>().unwrap().temperature, 20.0);
+    }
+
+    #[test]
+    fn long_gaps_count_as_one_window() {
+        let mut average = TimeWeightedAverage::new(100);
+        average.update(&measurements(10.0), 0);
+        let window = average.update(&measurements(20.0), 1000).unwrap();
+        assert_eq!(window.temperature, 10.0);
+        assert_eq!(average.average::<()>().unwrap().temperature, 10.0);
+        let window = average.update(&measurements(30.0), 1010).unwrap();
+        assert_eq!(window.temperature, 10.0);
+        assert_eq!(average.average::<()>().unwrap().temperature, 20.0);
+    }
+
+    #[test]
+    fn handles_wrapping_timestamps() {
+        let mut average = TimeWeightedAverage::new(100);
+        average.update(&measurements(10.0), u32::MAX - 49);
+        assert!(average.update(&measurements(20.0), 50).is_some());
+        average.reset();
+        assert!(average.average::<()>().is_none());
+    }
+}
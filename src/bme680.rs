@@ -0,0 +1,405 @@
+//! Driver for the Bosch BME680 and BME688, covering temperature, pressure, and humidity.
+//!
+//! The gas sensor is not supported and stays disabled. Apart from that, the BME680 shares the
+//! data layout and most of the configuration of the BME280, so this driver reuses
+//! [`Configuration`], [`Measurements`] and the [`Interface`] trait. The BME680 only offers forced
+//! mode, so the standby time of the configuration is ignored.
+//!
+//! The driver is generic over the interface. For I2C, use [`I2CInterface`]. SPI is not
+//! supported, as the BME680 pages its register map on that bus.
+//!
+//! ```no_run
+//! # use embedded_hal::{delay::DelayNs, i2c::I2c};
+//! # fn demo<I2C: I2c, D: DelayNs>(i2c: I2C, delay: &mut D) {
+//! use bme280::bme680::BME680;
+//! use bme280::i2c::I2CInterface;
+//!
+//! let mut bme680 = BME680::new(I2CInterface::new(i2c, 0x77));
+//! bme680.init(delay).unwrap();
+//! let measurements = bme680.measure(delay).unwrap();
+//! # }
+//! ```
+//!
+//! [`I2CInterface`]: crate::i2c::I2CInterface
+
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+#[cfg(feature = "async")]
+use super::AsyncInterface;
+use super::{
+    Configuration, Error, IIRFilter, Interface, Measurements, Operation, Oversampling, RawData,
+    BME280_CTRL_PRESS_MSK, BME280_CTRL_PRESS_POS, BME280_CTRL_TEMP_MSK, BME280_CTRL_TEMP_POS,
+    BME280_FILTER_MSK, BME280_FILTER_POS, BME280_H_CALIB_DATA_LEN, BME280_MEASUREMENT_TIMEOUT_MS,
+    BME280_P_T_CALIB_DATA_LEN, BME280_P_T_H_DATA_LEN, BME280_RESET_ADDR, BME280_SOFT_RESET_CMD,
+    BME280_TEMPERATURE_SKIPPED, BME280_TEMP_MAX, BME280_TEMP_MIN,
+};
+#[cfg(feature = "humidity")]
+use super::{
+    BME280_CTRL_HUM_MSK, BME280_HUMIDITY_MAX, BME280_HUMIDITY_MIN, BME280_HUMIDITY_SKIPPED,
+};
+#[cfg(feature = "pressure")]
+use super::{BME280_PRESSURE_MAX, BME280_PRESSURE_MIN, BME280_PRESSURE_SKIPPED};
+
+const BME680_CHIP_ID: u8 = 0x61;
+const BME680_CHIP_ID_ADDR: u8 = 0xD0;
+
+#[cfg(feature = "humidity")]
+const BME680_CTRL_HUM_ADDR: u8 = 0x72;
+const BME680_CTRL_MEAS_ADDR: u8 = 0x74;
+const BME680_CONFIG_ADDR: u8 = 0x75;
+
+const BME680_FORCED_MODE: u8 = 0x01;
+
+const BME680_MEAS_STATUS_ADDR: u8 = 0x1D;
+const BME680_NEW_DATA_MSK: u8 = 0x80;
+
+const BME680_DATA_ADDR: u8 = 0x1F;
+
+// The calibration data is spread over 0x89..0xA1 and 0xE1..0xEA. The second block is read in
+// two parts with the humidity calibration burst length of the interface.
+const BME680_COEFF1_ADDR: u8 = 0x89;
+const BME680_COEFF2_ADDR: u8 = 0xE1;
+const BME680_COEFF3_ADDR: u8 = 0xE8;
+
+// the reference driver waits 10ms after a soft reset
+const BME680_RESET_PERIOD_MS: u32 = 10;
+
+#[derive(Debug)]
+struct CalibrationData {
+    par_t1: u16,
+    par_t2: i16,
+    par_t3: i8,
+    #[cfg(feature = "pressure")]
+    par_p1: u16,
+    #[cfg(feature = "pressure")]
+    par_p2: i16,
+    #[cfg(feature = "pressure")]
+    par_p3: i8,
+    #[cfg(feature = "pressure")]
+    par_p4: i16,
+    #[cfg(feature = "pressure")]
+    par_p5: i16,
+    #[cfg(feature = "pressure")]
+    par_p6: i8,
+    #[cfg(feature = "pressure")]
+    par_p7: i8,
+    #[cfg(feature = "pressure")]
+    par_p8: i16,
+    #[cfg(feature = "pressure")]
+    par_p9: i16,
+    #[cfg(feature = "pressure")]
+    par_p10: u8,
+    #[cfg(feature = "humidity")]
+    par_h1: u16,
+    #[cfg(feature = "humidity")]
+    par_h2: u16,
+    #[cfg(feature = "humidity")]
+    par_h3: i8,
+    #[cfg(feature = "humidity")]
+    par_h4: i8,
+    #[cfg(feature = "humidity")]
+    par_h5: i8,
+    #[cfg(feature = "humidity")]
+    par_h6: u8,
+    #[cfg(feature = "humidity")]
+    par_h7: i8,
+}
+
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async", self = "AsyncBME680"))]
+/// Representation of a BME680 or BME688
+#[derive(Debug, Default)]
+pub struct BME680<I> {
+    /// Interface to the chip
+    interface: I,
+    /// calibration data
+    calibration: Option<CalibrationData>,
+    /// configuration last applied to the chip
+    config: Configuration,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs"),
+        Interface(sync = "Interface", async = "AsyncInterface")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME680")
+)]
+impl<I> BME680<I>
+where
+    I: Interface,
+{
+    /// Create a new BME680 struct on top of the given interface
+    pub fn new(interface: I) -> Self {
+        Self {
+            interface,
+            calibration: None,
+            config: Configuration::reset_state(),
+        }
+    }
+
+    /// Initializes the BME680.
+    /// This configures 2x temperature oversampling, 16x pressure oversampling, 1x humidity
+    /// oversampling, and the IIR filter coefficient 16.
+    pub async fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.init_with_config(
+            delay,
+            Configuration::default()
+                .with_humidity_oversampling(Oversampling::Oversampling1X)
+                .with_pressure_oversampling(Oversampling::Oversampling16X)
+                .with_temperature_oversampling(Oversampling::Oversampling2X)
+                .with_iir_filter(IIRFilter::Coefficient16),
+        )
+        .await
+    }
+
+    /// Initializes the BME680, applying the given configuration.
+    pub async fn init_with_config<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+    ) -> Result<(), Error<I::Error>> {
+        let chip_id = self.read_register(BME680_CHIP_ID_ADDR).await?;
+        if chip_id != BME680_CHIP_ID {
+            return Err(Error::UnexpectedChipId {
+                expected: BME680_CHIP_ID,
+                found: chip_id,
+            });
+        }
+
+        self.write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)
+            .await?;
+        delay.delay_ms(BME680_RESET_PERIOD_MS).await;
+
+        let coeff1 = self.read_pt_calib_data(BME680_COEFF1_ADDR).await?;
+        let coeff2 = self.read_h_calib_data(BME680_COEFF2_ADDR).await?;
+        let coeff3 = self.read_h_calib_data(BME680_COEFF3_ADDR).await?;
+        self.calibration = Some(parse_calib_data(&coeff1, &coeff2, &coeff3));
+
+        self.apply_config(config).await
+    }
+
+    /// Applies a new configuration without a full re-initialization.
+    /// The oversampling settings take effect with the next measurement.
+    pub async fn apply_config(&mut self, config: Configuration) -> Result<(), Error<I::Error>> {
+        // The sensor returns to sleep mode after each forced conversion, so the registers can be
+        // written at any time outside a measurement.
+        #[cfg(feature = "humidity")]
+        self.write_register(
+            BME680_CTRL_HUM_ADDR,
+            config.humidity_oversampling.bits() & BME280_CTRL_HUM_MSK,
+        )
+        .await?;
+        self.write_register(
+            BME680_CONFIG_ADDR,
+            (config.iir_filter.bits() << BME280_FILTER_POS) & BME280_FILTER_MSK,
+        )
+        .await?;
+
+        // Without the `humidity` feature, ctrl_hum keeps its reset value, which skips humidity.
+        #[cfg(not(feature = "humidity"))]
+        let config = config.with_humidity_oversampling(Oversampling::Skip);
+        // Without the `pressure` feature, the pressure measurement is skipped.
+        #[cfg(not(feature = "pressure"))]
+        let config = config.with_pressure_oversampling(Oversampling::Skip);
+        self.config = config;
+        Ok(())
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity
+    pub async fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        if self.calibration.is_none() {
+            return Err(Error::NotInitialized);
+        }
+        let config = self.config;
+        // As on the BME280, ctrl_meas needs to be written after ctrl_hum, and writing it
+        // with the forced mode bits starts the conversion.
+        let ctrl_meas = ((config.temperature_oversampling.bits() << BME280_CTRL_TEMP_POS)
+            & BME280_CTRL_TEMP_MSK)
+            | ((config.pressure_oversampling.bits() << BME280_CTRL_PRESS_POS)
+                & BME280_CTRL_PRESS_MSK)
+            | BME680_FORCED_MODE;
+        self.write_register(BME680_CTRL_MEAS_ADDR, ctrl_meas)
+            .await?;
+
+        // Wait for the conversion time given by the reference driver, then poll for new data.
+        let cycles = config.temperature_oversampling.factor()
+            + config.pressure_oversampling.factor()
+            + config.humidity_oversampling.factor();
+        let duration_us = cycles * 1963 + 477 * 9 + 500;
+        delay.delay_us(duration_us).await;
+        let mut elapsed_ms = duration_us / 1000;
+        loop {
+            let status = self.read_register(BME680_MEAS_STATUS_ADDR).await?;
+            if status & BME680_NEW_DATA_MSK != 0 {
+                break;
+            }
+            if elapsed_ms >= BME280_MEASUREMENT_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+
+        let data = RawData::parse(self.read_data(BME680_DATA_ADDR).await?);
+        let calibration = self.calibration.as_ref().ok_or(Error::NotInitialized)?;
+        Ok(compensate(data, calibration))
+    }
+
+    // The bus accesses tag their errors with the operation, see `Error::BusOperation`.
+
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<I::Error>> {
+        let result = self.interface.read_register(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn read_pt_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_pt_calib_data(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn read_h_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_h_calib_data(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I::Error>> {
+        let result = self.interface.write_register(register, payload).await;
+        result.map_err(|e| e.during(Operation::Write(register)))
+    }
+
+    async fn read_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_data(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+}
+
+fn compensate<E>(data: RawData, calibration: &CalibrationData) -> Measurements<E> {
+    if data.temperature == BME280_TEMPERATURE_SKIPPED {
+        return Measurements::from_channels([f32::NAN; 3]);
+    }
+
+    let var1 = (data.temperature as f32 / 16384.0 - calibration.par_t1 as f32 / 1024.0)
+        * calibration.par_t2 as f32;
+    let var2 = data.temperature as f32 / 131072.0 - calibration.par_t1 as f32 / 8192.0;
+    let var2 = var2 * var2 * calibration.par_t3 as f32 * 16.0;
+    let t_fine = var1 + var2;
+    let temperature = (t_fine / 5120.0).clamp(BME280_TEMP_MIN, BME280_TEMP_MAX);
+
+    #[cfg(feature = "pressure")]
+    let pressure = if data.pressure == BME280_PRESSURE_SKIPPED {
+        f32::NAN
+    } else {
+        compensate_pressure(data.pressure, t_fine, calibration)
+    };
+    #[cfg(not(feature = "pressure"))]
+    let pressure = f32::NAN;
+    #[cfg(feature = "humidity")]
+    let humidity = if data.humidity == BME280_HUMIDITY_SKIPPED {
+        f32::NAN
+    } else {
+        compensate_humidity(data.humidity, t_fine, calibration)
+    };
+    #[cfg(not(feature = "humidity"))]
+    let humidity = f32::NAN;
+
+    Measurements::from_channels([temperature, pressure, humidity])
+}
+
+#[cfg(feature = "pressure")]
+fn compensate_pressure(uncompensated: u32, t_fine: f32, calibration: &CalibrationData) -> f32 {
+    let var1 = t_fine / 2.0 - 64000.0;
+    let var2 = var1 * var1 * calibration.par_p6 as f32 / 131072.0;
+    let var2 = var2 + var1 * calibration.par_p5 as f32 * 2.0;
+    let var2 = var2 / 4.0 + calibration.par_p4 as f32 * 65536.0;
+    let var1 = (calibration.par_p3 as f32 * var1 * var1 / 16384.0
+        + calibration.par_p2 as f32 * var1)
+        / 524288.0;
+    let var1 = (1.0 + var1 / 32768.0) * calibration.par_p1 as f32;
+    if var1 == 0.0 {
+        return f32::NAN;
+    }
+
+    let pressure = 1048576.0 - uncompensated as f32;
+    let pressure = (pressure - var2 / 4096.0) * 6250.0 / var1;
+    let var1 = calibration.par_p9 as f32 * pressure * pressure / 2147483648.0;
+    let var2 = pressure * calibration.par_p8 as f32 / 32768.0;
+    let var3 = pressure / 256.0;
+    let var3 = var3 * var3 * var3 * calibration.par_p10 as f32 / 131072.0;
+    let pressure = pressure + (var1 + var2 + var3 + calibration.par_p7 as f32 * 128.0) / 16.0;
+    pressure.clamp(BME280_PRESSURE_MIN, BME280_PRESSURE_MAX)
+}
+
+#[cfg(feature = "humidity")]
+fn compensate_humidity(uncompensated: u32, t_fine: f32, calibration: &CalibrationData) -> f32 {
+    let temperature = t_fine / 5120.0;
+    let var1 = uncompensated as f32
+        - (calibration.par_h1 as f32 * 16.0 + calibration.par_h3 as f32 / 2.0 * temperature);
+    let var2 = var1
+        * (calibration.par_h2 as f32 / 262144.0
+            * (1.0
+                + calibration.par_h4 as f32 / 16384.0 * temperature
+                + calibration.par_h5 as f32 / 1048576.0 * temperature * temperature));
+    let var3 = calibration.par_h6 as f32 / 16384.0;
+    let var4 = calibration.par_h7 as f32 / 2097152.0;
+    let humidity = var2 + (var3 + var4 * temperature) * var2 * var2;
+    humidity.clamp(BME280_HUMIDITY_MIN, BME280_HUMIDITY_MAX)
+}
+
+#[cfg_attr(not(feature = "humidity"), allow(unused_variables))]
+fn parse_calib_data(coeff1: &[u8], coeff2: &[u8], coeff3: &[u8]) -> CalibrationData {
+    let le = |lsb: u8, msb: u8| u16::from_le_bytes([lsb, msb]);
+    CalibrationData {
+        par_t1: le(coeff3[1], coeff3[2]),
+        par_t2: le(coeff1[1], coeff1[2]) as i16,
+        par_t3: coeff1[3] as i8,
+        #[cfg(feature = "pressure")]
+        par_p1: le(coeff1[5], coeff1[6]),
+        #[cfg(feature = "pressure")]
+        par_p2: le(coeff1[7], coeff1[8]) as i16,
+        #[cfg(feature = "pressure")]
+        par_p3: coeff1[9] as i8,
+        #[cfg(feature = "pressure")]
+        par_p4: le(coeff1[11], coeff1[12]) as i16,
+        #[cfg(feature = "pressure")]
+        par_p5: le(coeff1[13], coeff1[14]) as i16,
+        #[cfg(feature = "pressure")]
+        par_p6: coeff1[16] as i8,
+        #[cfg(feature = "pressure")]
+        par_p7: coeff1[15] as i8,
+        #[cfg(feature = "pressure")]
+        par_p8: le(coeff1[19], coeff1[20]) as i16,
+        #[cfg(feature = "pressure")]
+        par_p9: le(coeff1[21], coeff1[22]) as i16,
+        #[cfg(feature = "pressure")]
+        par_p10: coeff1[23],
+        #[cfg(feature = "humidity")]
+        par_h1: (coeff2[2] as u16) << 4 | (coeff2[1] & 0x0F) as u16,
+        #[cfg(feature = "humidity")]
+        par_h2: (coeff2[0] as u16) << 4 | (coeff2[1] >> 4) as u16,
+        #[cfg(feature = "humidity")]
+        par_h3: coeff2[3] as i8,
+        #[cfg(feature = "humidity")]
+        par_h4: coeff2[4] as i8,
+        #[cfg(feature = "humidity")]
+        par_h5: coeff2[5] as i8,
+        #[cfg(feature = "humidity")]
+        par_h6: coeff2[6],
+        #[cfg(feature = "humidity")]
+        par_h7: coeff3[0] as i8,
+    }
+}
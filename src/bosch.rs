@@ -0,0 +1,183 @@
+//! Integer compensation matching the Bosch BME280 C API bit for bit.
+//!
+//! The formulas are the ones of the integer build of the reference driver, including its use of
+//! truncating divisions where the datasheet uses shifts, so that firmware ported from C produces
+//! identical numbers during validation.
+
+#[cfg(feature = "humidity")]
+use super::BME280_HUMIDITY_SKIPPED;
+#[cfg(feature = "pressure")]
+use super::BME280_PRESSURE_SKIPPED;
+use super::{CalibrationData, RawData, BME280_TEMPERATURE_SKIPPED};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const TEMPERATURE_MIN: i32 = -4000;
+const TEMPERATURE_MAX: i32 = 8500;
+#[cfg(feature = "pressure")]
+const PRESSURE_MIN: i64 = 30000 * 256;
+#[cfg(feature = "pressure")]
+const PRESSURE_MAX: i64 = 110000 * 256;
+#[cfg(feature = "humidity")]
+const HUMIDITY_MAX: u32 = 102400;
+
+/// Measurement data in the integer formats of the Bosch C API
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoschMeasurements {
+    /// temperature in hundredths of a degree celsius, `None` if the temperature measurement is
+    /// skipped
+    pub temperature: Option<i32>,
+    /// pressure in 1/256 pascals (Q24.8 format), `None` if the pressure or temperature
+    /// measurement is skipped. Like the C API, this is the lower limit of 300 hPa if the
+    /// calibration data is invalid.
+    pub pressure: Option<u32>,
+    /// humidity in 1/1024 percent relative humidity (Q22.10 format), `None` if the chip has no
+    /// humidity sensor (BMP280) or the humidity or temperature measurement is skipped
+    pub humidity: Option<u32>,
+}
+
+/// Compensates the raw ADC values of a measurement like the Bosch C API
+pub fn compensate(data: RawData, calibration: &CalibrationData) -> BoschMeasurements {
+    let t_fine = match t_fine(data.temperature, calibration) {
+        Some(t_fine) => t_fine,
+        None => {
+            return BoschMeasurements {
+                temperature: None,
+                pressure: None,
+                humidity: None,
+            }
+        }
+    };
+
+    let temperature = ((t_fine * 5 + 128) / 256).clamp(TEMPERATURE_MIN, TEMPERATURE_MAX);
+    BoschMeasurements {
+        temperature: Some(temperature),
+        pressure: compensate_pressure(data.pressure, t_fine, calibration),
+        humidity: compensate_humidity(data.humidity, t_fine, calibration),
+    }
+}
+
+fn t_fine(uncompensated: u32, calibration: &CalibrationData) -> Option<i32> {
+    if uncompensated == BME280_TEMPERATURE_SKIPPED {
+        return None;
+    }
+    let uncompensated = uncompensated as i32;
+    let var1 = uncompensated / 8 - calibration.dig_t1 as i32 * 2;
+    let var1 = (var1 * calibration.dig_t2 as i32) / 2048;
+    let var2 = uncompensated / 16 - calibration.dig_t1 as i32;
+    let var2 = (((var2 * var2) / 4096) * calibration.dig_t3 as i32) / 16384;
+    Some(var1 + var2)
+}
+
+#[cfg(feature = "pressure")]
+fn compensate_pressure(
+    uncompensated: u32,
+    t_fine: i32,
+    calibration: &CalibrationData,
+) -> Option<u32> {
+    if uncompensated == BME280_PRESSURE_SKIPPED {
+        return None;
+    }
+    let var1 = t_fine as i64 - 128000;
+    let var2 = var1 * var1 * calibration.dig_p6 as i64;
+    let var2 = var2 + ((var1 * calibration.dig_p5 as i64) * 131072);
+    let var2 = var2 + (calibration.dig_p4 as i64 * 34359738368);
+    let var1 = ((var1 * var1 * calibration.dig_p3 as i64) / 256)
+        + (var1 * calibration.dig_p2 as i64 * 4096);
+    let var1 = ((140737488355328 + var1) * calibration.dig_p1 as i64) / 8589934592;
+    // avoids a division by zero with invalid calibration data, like the C API
+    if var1 == 0 {
+        return Some(PRESSURE_MIN as u32);
+    }
+
+    let var4 = 1048576 - uncompensated as i64;
+    let var4 = (((var4 * 2147483648) - var2) * 3125) / var1;
+    let var1 = (calibration.dig_p9 as i64 * (var4 / 8192) * (var4 / 8192)) / 33554432;
+    let var2 = (calibration.dig_p8 as i64 * var4) / 524288;
+    let var4 = ((var4 + var1 + var2) / 256) + (calibration.dig_p7 as i64 * 16);
+    Some(var4.clamp(PRESSURE_MIN, PRESSURE_MAX) as u32)
+}
+
+#[cfg(not(feature = "pressure"))]
+fn compensate_pressure(
+    _uncompensated: u32,
+    _t_fine: i32,
+    _calibration: &CalibrationData,
+) -> Option<u32> {
+    None
+}
+
+#[cfg(feature = "humidity")]
+fn compensate_humidity(
+    uncompensated: u32,
+    t_fine: i32,
+    calibration: &CalibrationData,
+) -> Option<u32> {
+    if uncompensated == BME280_HUMIDITY_SKIPPED {
+        return None;
+    }
+    let var1 = t_fine - 76800;
+    let var2 = uncompensated as i32 * 16384;
+    let var3 = calibration.dig_h4 as i32 * 1048576;
+    let var4 = calibration.dig_h5 as i32 * var1;
+    let var5 = (((var2 - var3) - var4) + 16384) / 32768;
+    let var2 = (var1 * calibration.dig_h6 as i32) / 1024;
+    let var3 = (var1 * calibration.dig_h3 as i32) / 2048;
+    let var4 = ((var2 * (var3 + 32768)) / 1024) + 2097152;
+    let var2 = ((var4 * calibration.dig_h2 as i32) + 8192) / 16384;
+    let var3 = var5 * var2;
+    let var4 = ((var3 / 32768) * (var3 / 32768)) / 128;
+    let var5 = var3 - ((var4 * calibration.dig_h1 as i32) / 16);
+    let humidity = (var5.clamp(0, 419430400) / 4096) as u32;
+    Some(humidity.min(HUMIDITY_MAX))
+}
+
+#[cfg(not(feature = "humidity"))]
+fn compensate_humidity(
+    _uncompensated: u32,
+    _t_fine: i32,
+    _calibration: &CalibrationData,
+) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "pressure", feature = "humidity"))]
+mod tests {
+    use super::*;
+    use crate::test_vectors::VECTORS;
+
+    #[test]
+    fn matches_the_reference_vectors() {
+        let expected = [
+            (Some(2508), Some(25767240), None),
+            (Some(2338), Some(23927438), Some(40147)),
+            (Some(1142), Some(23021649), Some(66818)),
+            (Some(3323), Some(24721342), Some(33334)),
+        ];
+        assert_eq!(VECTORS.len(), expected.len());
+        for (vector, (temperature, pressure, humidity)) in VECTORS.iter().zip(expected) {
+            let calibration = CalibrationData::from_bytes(&vector.calibration);
+            assert_eq!(
+                compensate(vector.raw, &calibration),
+                BoschMeasurements {
+                    temperature,
+                    pressure,
+                    humidity,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_calibration_gives_the_pressure_minimum() {
+        let mut bytes = VECTORS[0].calibration;
+        // dig_P1
+        bytes[6] = 0;
+        bytes[7] = 0;
+        let calibration = CalibrationData::from_bytes(&bytes);
+        let measurements = compensate(VECTORS[0].raw, &calibration);
+        assert_eq!(measurements.pressure, Some(PRESSURE_MIN as u32));
+    }
+}
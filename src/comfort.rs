@@ -0,0 +1,83 @@
+//! Classification of the thermal comfort of a room.
+//!
+//! ```
+//! use bme280::comfort::{Comfort, ComfortZone};
+//!
+//! let zone = ComfortZone::default().with_temperature(21.0, 24.0);
+//! assert_eq!(zone.classify(22.0, 45.0), Comfort::Comfortable);
+//! assert_eq!(zone.classify(22.0, 75.0), Comfort::TooHumid);
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Comfort of the conditions relative to a [`ComfortZone`]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Comfort {
+    /// within the envelope
+    Comfortable,
+    /// above the highest humidity
+    TooHumid,
+    /// below the lowest humidity
+    TooDry,
+    /// above the highest temperature
+    TooHot,
+    /// below the lowest temperature
+    TooCold,
+}
+
+/// Envelope of comfortable temperatures and relative humidities
+///
+/// The default is 20 °C to 26 °C and 30 % to 60 % relative humidity, close to the summer and
+/// winter comfort zones of ASHRAE 55 for office work.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ComfortZone {
+    temperature_min: f32,
+    temperature_max: f32,
+    humidity_min: f32,
+    humidity_max: f32,
+}
+
+impl Default for ComfortZone {
+    fn default() -> Self {
+        ComfortZone {
+            temperature_min: 20.0,
+            temperature_max: 26.0,
+            humidity_min: 30.0,
+            humidity_max: 60.0,
+        }
+    }
+}
+
+impl ComfortZone {
+    /// Sets the range of comfortable temperatures in degrees celsius.
+    pub fn with_temperature(mut self, min: f32, max: f32) -> Self {
+        self.temperature_min = min;
+        self.temperature_max = max;
+        self
+    }
+
+    /// Sets the range of comfortable relative humidities in percent.
+    pub fn with_humidity(mut self, min: f32, max: f32) -> Self {
+        self.humidity_min = min;
+        self.humidity_max = max;
+        self
+    }
+
+    /// Classifies the `temperature` and the relative `humidity`. The temperature takes precedence
+    /// when both are outside the envelope.
+    pub fn classify(&self, temperature: f32, humidity: f32) -> Comfort {
+        if temperature > self.temperature_max {
+            Comfort::TooHot
+        } else if temperature < self.temperature_min {
+            Comfort::TooCold
+        } else if humidity > self.humidity_max {
+            Comfort::TooHumid
+        } else if humidity < self.humidity_min {
+            Comfort::TooDry
+        } else {
+            Comfort::Comfortable
+        }
+    }
+}
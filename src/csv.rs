@@ -0,0 +1,91 @@
+//! CSV logging of measurements.
+//!
+//! ```
+//! use bme280::csv::CsvWriter;
+//!
+//! let mut csv = CsvWriter::with_timestamps(Vec::new());
+//! # let measurements: bme280::Measurements<()> = bme280::Measurements::unpack(&[0; 6]);
+//! csv.write(&measurements, Some(1700000000)).unwrap();
+//! let csv = String::from_utf8(csv.into_inner()).unwrap();
+//! assert!(csv.starts_with("timestamp,temperature,pressure,humidity\n"));
+//! ```
+
+use std::io::{self, Write};
+
+use super::Measurements;
+
+/// Writer of measurements as CSV records, preceded by a header record
+///
+/// The columns are the temperature in degrees celsius, the pressure in pascals, and the percent
+/// relative humidity, optionally preceded by a timestamp. Unavailable values are left empty.
+#[derive(Debug)]
+pub struct CsvWriter<W> {
+    writer: W,
+    timestamps: bool,
+    header_written: bool,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Create a new CSV writer without a timestamp column
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer,
+            timestamps: false,
+            header_written: false,
+        }
+    }
+
+    /// Create a new CSV writer with a leading timestamp column
+    pub fn with_timestamps(writer: W) -> Self {
+        CsvWriter {
+            timestamps: true,
+            ..CsvWriter::new(writer)
+        }
+    }
+
+    /// Write a record of the `measurements`, and the header before the first one. The
+    /// `timestamp`, in a unit chosen by the caller, is ignored without a timestamp column.
+    pub fn write<E>(
+        &mut self,
+        measurements: &Measurements<E>,
+        timestamp: Option<u64>,
+    ) -> io::Result<()> {
+        if !self.header_written {
+            if self.timestamps {
+                self.writer.write_all(b"timestamp,")?;
+            }
+            self.writer.write_all(b"temperature,pressure,humidity\n")?;
+            self.header_written = true;
+        }
+
+        if self.timestamps {
+            if let Some(timestamp) = timestamp {
+                write!(self.writer, "{}", timestamp)?;
+            }
+            self.writer.write_all(b",")?;
+        }
+        write_value(&mut self.writer, Some(measurements.temperature))?;
+        self.writer.write_all(b",")?;
+        write_value(&mut self.writer, Some(measurements.pressure))?;
+        self.writer.write_all(b",")?;
+        write_value(&mut self.writer, measurements.humidity)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Flush the underlying writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Return the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn write_value<W: Write>(writer: &mut W, value: Option<f32>) -> io::Result<()> {
+    match value {
+        Some(value) if !value.is_nan() => write!(writer, "{}", value),
+        _ => Ok(()),
+    }
+}
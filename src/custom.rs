@@ -0,0 +1,496 @@
+//! BME280 driver for sensors attached via a user-provided transport.
+//!
+//! Implement [`Interface`] (or `AsyncInterface` with the `async` feature) for the transport and
+//! pass it to [`BME280::new`].
+
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+#[cfg(feature = "async")]
+use futures_util::Stream;
+
+#[cfg(feature = "integer")]
+use super::bosch::BoschMeasurements;
+#[cfg(feature = "embedded-sensors-hal")]
+use super::sensors;
+use super::traits;
+#[cfg(feature = "async")]
+use super::AsyncBME280Common;
+#[cfg(feature = "async")]
+use super::AsyncInterface;
+#[cfg(feature = "f64")]
+use super::DoubleMeasurements;
+#[cfg(feature = "with_fixed")]
+use super::FixedMeasurements;
+#[cfg(feature = "uom")]
+use super::QuantityMeasurements;
+use super::{
+    BME280Common, CalibrationData, ChipVariant, Configuration, Error, IIRFilter, Interface,
+    MeasurementStatistics, Measurements, Offsets, Oversampling, RawData, SensorMode,
+};
+#[cfg(feature = "integer")]
+use super::{IntegerMeasurements, MeasurementsMilli};
+
+#[maybe_async_cfg::maybe(
+    idents(BME280Common(sync = "BME280Common", async = "AsyncBME280Common")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
+/// Representation of a BME280
+#[derive(Debug, Default)]
+pub struct BME280<I> {
+    common: BME280Common<I>,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        BME280Common(sync = "BME280Common", async = "AsyncBME280Common"),
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs"),
+        Interface(sync = "Interface", async = "AsyncInterface")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
+impl<I> BME280<I>
+where
+    I: Interface,
+{
+    /// Create a new BME280 struct on top of the given interface
+    pub fn new(interface: I) -> Self {
+        Self {
+            common: BME280Common::new(interface),
+        }
+    }
+
+    /// Releases the interface, e.g. to repurpose it, or to power-gate the sensor and create the
+    /// driver again later. The sensor is left in whatever mode it is currently in.
+    pub fn release(self) -> I {
+        self.common.interface
+    }
+
+    /// Initializes the BME280.
+    /// This configures 2x temperature oversampling, 16x pressure oversampling, and the IIR filter
+    /// coefficient 16.
+    pub async fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common
+            .init(
+                delay,
+                Configuration::default()
+                    .with_humidity_oversampling(Oversampling::Oversampling1X)
+                    .with_pressure_oversampling(Oversampling::Oversampling16X)
+                    .with_temperature_oversampling(Oversampling::Oversampling2X)
+                    .with_iir_filter(IIRFilter::Coefficient16),
+            )
+            .await
+    }
+
+    /// Initializes the BME280, applying the given configuration.
+    /// See [Initialization](crate#initialization).
+    pub async fn init_with_config<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+    ) -> Result<(), Error<I::Error>> {
+        self.common.init(delay, config).await
+    }
+
+    /// Soft-resets the BME280 and reloads its calibration data.
+    /// See [Initialization](crate#initialization).
+    pub async fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common.reset(delay).await
+    }
+
+    /// Heals the BME280 after a bus fault or a brown-out, see [Recovery](crate#recovery).
+    pub async fn recover<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common.recover(delay).await
+    }
+
+    /// Runs a self test of the initialized BME280, see [Self test](crate#self-test).
+    pub async fn self_test<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common.self_test(delay).await
+    }
+
+    /// Applies a new configuration without a full re-initialization.
+    /// See [Configuration](crate#configuration).
+    pub async fn apply_config(&mut self, config: Configuration) -> Result<(), Error<I::Error>> {
+        self.common.apply_config(config).await
+    }
+
+    /// Changes the oversampling settings, keeping the rest of the configuration.
+    pub async fn set_oversampling(
+        &mut self,
+        temperature: Oversampling,
+        pressure: Oversampling,
+        humidity: Oversampling,
+    ) -> Result<(), Error<I::Error>> {
+        self.common
+            .set_oversampling(temperature, pressure, humidity)
+            .await
+    }
+
+    /// Changes the IIR filter setting, keeping the rest of the configuration.
+    pub async fn set_iir_filter(&mut self, filter: IIRFilter) -> Result<(), Error<I::Error>> {
+        self.common.set_iir_filter(filter).await
+    }
+
+    /// Checks whether the BME280 still holds the configuration applied by the last `init`.
+    /// Returns `false` if the registers were reverted, e.g. by a brown-out of the sensor.
+    pub async fn verify_config(&mut self) -> Result<bool, Error<I::Error>> {
+        self.common.verify_config().await
+    }
+
+    /// Reads the register at `address`, for registers the rest of the API does not cover
+    pub async fn read_register(&mut self, address: u8) -> Result<u8, Error<I::Error>> {
+        self.common.read_register(address).await
+    }
+
+    /// Writes `value` to the register at `address`, for registers the rest of the API does not
+    /// cover. See [Configuration](crate#configuration).
+    pub async fn write_register(&mut self, address: u8, value: u8) -> Result<(), Error<I::Error>> {
+        self.common.write_raw_register(address, value).await
+    }
+
+    /// Enables or disables verifying the configuration before each forced measurement.
+    /// See [Recovery](crate#recovery).
+    pub fn set_config_check(&mut self, enabled: bool) {
+        self.common.check_config = enabled;
+    }
+
+    /// Enables or disables the range check of the compensated measurements.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_range_check(&mut self, enabled: bool) {
+        self.common.check_range = enabled;
+    }
+
+    /// Enables the detection of a hung sensor after `frames` identical conversions.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_stuck_detection(&mut self, frames: u8) {
+        self.common.stuck.limit = frames;
+    }
+
+    /// Returns the chip variant identified by `init`, or `None` if the chip ID has not been read
+    /// yet. An unrecognized chip ID is reported even though `init` fails for it.
+    pub fn variant(&self) -> Option<ChipVariant> {
+        self.common.variant
+    }
+
+    /// Sets offsets that are added to the measurements after compensation.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_offsets(&mut self, temperature: f32, pressure: f32, humidity: f32) {
+        self.common.offsets = Offsets {
+            temperature,
+            pressure,
+            humidity,
+            ..self.common.offsets
+        };
+    }
+
+    /// Compensates the self-heating of a sensor that reads `offset` degrees celsius too high.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_self_heating(&mut self, offset: f32) {
+        self.common.offsets.self_heating = offset;
+    }
+
+    /// Returns the calibration coefficients read by `init`, or `None` before initialization
+    pub fn calibration(&self) -> Option<&CalibrationData> {
+        self.common.calibration.as_ref()
+    }
+
+    /// Makes `init` accept the non-standard chip ID of a compatible clone part
+    pub fn expect_chip_id(&mut self, chip_id: u8, compatible: ChipVariant) {
+        self.common.chip_id_override = Some((chip_id, compatible));
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity
+    pub async fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.common.measure(delay).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity, returning
+    /// [`Error::Timeout`] if the conversion does not finish within `timeout_ms` milliseconds
+    pub async fn measure_with_timeout<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.common.measure_with_timeout(delay, timeout_ms).await
+    }
+
+    /// Captures sensor data and returns the uncompensated ADC values, e.g. to log raw frames
+    /// and compensate them later
+    pub async fn measure_raw<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RawData, Error<I::Error>> {
+        self.common.measure_raw(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation formulas of the
+    /// datasheet, which avoid floating point on targets without an FPU
+    #[cfg(feature = "integer")]
+    pub async fn measure_integer<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<IntegerMeasurements, Error<I::Error>> {
+        self.common.measure_integer(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation of the Bosch C API,
+    /// giving bit-identical results in its units
+    #[cfg(feature = "integer")]
+    pub async fn measure_bosch<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<BoschMeasurements, Error<I::Error>> {
+        self.common.measure_bosch(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation, in
+    /// thousandths of a degree celsius, pascals, and thousandths of a percent relative humidity
+    #[cfg(feature = "integer")]
+    pub async fn measure_milli<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<MeasurementsMilli, Error<I::Error>> {
+        self.common.measure_milli(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only with the 64-bit integer
+    /// compensation, in 1/256 pascals (Q24.8 format)
+    #[cfg(feature = "integer")]
+    pub async fn measure_pressure_64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<u32>, Error<I::Error>> {
+        self.common.measure_pressure_64(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation, returning fixed-point
+    /// numbers for code that avoids floating point
+    #[cfg(feature = "with_fixed")]
+    pub async fn measure_fixed<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<FixedMeasurements, Error<I::Error>> {
+        self.common.measure_fixed(delay).await
+    }
+
+    /// Captures and processes sensor data into typed quantities of the `uom` crate
+    #[cfg(feature = "uom")]
+    pub async fn measure_quantities<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<QuantityMeasurements, Error<I::Error>> {
+        self.common.measure_quantities(delay).await
+    }
+
+    /// Captures and processes sensor data with the double-precision floating point
+    /// compensation formulas of the datasheet
+    #[cfg(feature = "f64")]
+    pub async fn measure_f64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<DoubleMeasurements, Error<I::Error>> {
+        self.common.measure_f64(delay).await
+    }
+
+    /// Performs `n` forced conversions and returns the mean, minimum, and maximum of each
+    /// channel
+    pub async fn measure_n<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        n: u16,
+    ) -> Result<MeasurementStatistics<I::Error>, Error<I::Error>> {
+        self.common.measure_n(delay, n).await
+    }
+
+    /// Captures sensor data and processes the temperature only, in degrees celsius
+    pub async fn measure_temperature<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I::Error>> {
+        self.common.measure_temperature(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only, in pascals
+    pub async fn measure_pressure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I::Error>> {
+        self.common.measure_pressure(delay).await
+    }
+
+    /// Captures sensor data and processes the relative humidity only, in percent.
+    /// Returns `None` if the chip has no humidity sensor or the humidity measurement is skipped.
+    pub async fn measure_humidity<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<f32>, Error<I::Error>> {
+        self.common.measure_humidity(delay).await
+    }
+
+    /// Puts the BME280 into normal mode, in which it cycles between conversions and standby
+    /// periods on its own. Use [`read_latest`](Self::read_latest) to fetch the samples.
+    pub async fn set_normal_mode(&mut self) -> Result<(), Error<I::Error>> {
+        self.common.normal().await
+    }
+
+    /// Reads the current operating mode back from the device.
+    pub async fn mode(&mut self) -> Result<SensorMode, Error<I::Error>> {
+        self.common.mode().await
+    }
+
+    /// Puts the BME280 into sleep mode.
+    /// Only the mode bits are changed, so the configuration is retained while sleeping.
+    pub async fn sleep(&mut self) -> Result<(), Error<I::Error>> {
+        self.common.sleep().await
+    }
+
+    /// Wakes the BME280 from sleep mode by putting it back into normal mode.
+    /// Only the mode bits are changed, so measurements resume with the existing configuration.
+    pub async fn wake(&mut self) -> Result<(), Error<I::Error>> {
+        self.common.wake().await
+    }
+
+    /// Triggers a single conversion in forced mode and returns without waiting for it.
+    /// Collect the result with [`read_triggered`](Self::read_triggered).
+    pub async fn trigger<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common.start_conversion(delay).await
+    }
+
+    /// Waits for the conversion started with [`trigger`](Self::trigger) to finish, then reads
+    /// and processes its result.
+    pub async fn read_triggered<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.common.read_triggered(delay).await
+    }
+
+    /// Returns whether a conversion is currently running, based on the status register.
+    /// This is a cheap way to check whether a triggered conversion has finished.
+    pub async fn is_measuring(&mut self) -> Result<bool, Error<I::Error>> {
+        self.common.is_measuring().await
+    }
+
+    /// Reads and processes the most recent sample without triggering a new conversion.
+    /// This is intended for normal mode, where the sensor keeps its data registers up to date.
+    pub async fn read_latest(&mut self) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.common.read_latest().await
+    }
+}
+
+impl<I> BME280<I>
+where
+    I: Interface,
+{
+    /// Returns an endless iterator over the samples of a BME280 running in normal mode.
+    /// See [Normal mode](crate#normal-mode).
+    pub fn samples<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> impl Iterator<Item = Result<Measurements<I::Error>, Error<I::Error>>> + 'a {
+        self.common.samples(delay)
+    }
+
+    /// Starts a forced conversion without blocking.
+    /// Poll [`read_measurement`](Self::read_measurement) to collect the result.
+    pub fn start_measurement<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.common.start_measurement(delay)
+    }
+
+    /// Returns the result of the conversion started with
+    /// [`start_measurement`](Self::start_measurement), or `nb::Error::WouldBlock` while it runs.
+    pub fn read_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> nb::Result<Measurements<I::Error>, Error<I::Error>> {
+        self.common.read_measurement(delay)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I> AsyncBME280<I>
+where
+    I: AsyncInterface,
+{
+    /// Turns the driver into an endless stream over the samples of a BME280 running in normal
+    /// mode. See [Normal mode](crate#normal-mode).
+    pub fn into_stream<D: AsyncDelayNs>(
+        self,
+        delay: D,
+    ) -> impl Stream<Item = Result<Measurements<I::Error>, Error<I::Error>>> {
+        self.common.into_stream(delay)
+    }
+}
+
+impl<I> traits::Thermometer for BME280<I>
+where
+    I: Interface,
+{
+    type Error = Error<I::Error>;
+
+    fn temperature(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_temperature(&mut delay)
+    }
+}
+
+impl<I> traits::Barometer for BME280<I>
+where
+    I: Interface,
+{
+    type Error = Error<I::Error>;
+
+    fn pressure(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_pressure(&mut delay)
+    }
+}
+
+impl<I> traits::Hygrometer for BME280<I>
+where
+    I: Interface,
+{
+    type Error = Error<I::Error>;
+
+    fn humidity(&mut self, mut delay: &mut dyn DelayNs) -> Result<Option<f32>, Self::Error> {
+        self.measure_humidity(&mut delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I, D> embedded_sensors_hal::sensor::ErrorType for sensors::Sensor<BME280<I>, D>
+where
+    I: Interface,
+    I::Error: core::fmt::Debug,
+{
+    type Error = Error<I::Error>;
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I, D> embedded_sensors_hal::temperature::TemperatureSensor for sensors::Sensor<BME280<I>, D>
+where
+    I: Interface,
+    I::Error: core::fmt::Debug,
+    D: DelayNs,
+{
+    fn temperature(&mut self) -> Result<f32, Self::Error> {
+        self.device.measure_temperature(&mut self.delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I, D> embedded_sensors_hal::humidity::RelativeHumiditySensor for sensors::Sensor<BME280<I>, D>
+where
+    I: Interface,
+    I::Error: core::fmt::Debug,
+    D: DelayNs,
+{
+    fn relative_humidity(&mut self) -> Result<f32, Self::Error> {
+        self.device
+            .measure_humidity(&mut self.delay)?
+            .ok_or(Error::InvalidData)
+    }
+}
@@ -0,0 +1,236 @@
+//! Quantities derived from the measurements.
+//!
+//! Temperatures are in degrees celsius, pressures in pascals, relative humidities in percent,
+//! and altitudes in meters, like the measurements themselves.
+
+use super::isa;
+
+/// Standard atmospheric pressure at sea level, in pascals
+pub const SEA_LEVEL_PRESSURE: f32 = 101325.0;
+
+// coefficients of the Magnus formula over water, after Sonntag (1990)
+const MAGNUS_B: f32 = 17.62;
+const MAGNUS_C: f32 = 243.12;
+const MAGNUS_A: f32 = 611.2;
+
+// coefficients of the Magnus formula over ice, after Sonntag (1990)
+const MAGNUS_ICE_B: f32 = 22.46;
+const MAGNUS_ICE_C: f32 = 272.62;
+
+/// Specific gas constant of dry air, in J/(kg·K)
+const R_DRY_AIR: f32 = 287.05;
+
+/// Ratio of the molar gas constant to the enthalpy of vaporization of water at 100 °C, per kelvin
+const R_OVER_VAPORIZATION: f32 = 8.314_463 / 40660.0;
+
+/// Standard acceleration of gravity, in m/s²
+const GRAVITY: f32 = 9.80665;
+
+/// Specific gas constant of water vapour, in J/(kg·K)
+const R_VAPOR: f32 = 461.5;
+
+/// Absolute temperature of 0 °C, in kelvin
+pub(crate) const ZERO_CELSIUS: f32 = 273.15;
+
+/// Altitude at which the `pressure` is measured, given the `sea_level_pressure`, after the
+/// international barometric formula
+pub fn altitude(pressure: f32, sea_level_pressure: f32) -> f32 {
+    44330.0 * (1.0 - libm::powf(pressure / sea_level_pressure, 1.0 / 5.255))
+}
+
+/// Pressure reduced to sea level (QNH) from the `pressure` measured at a station at the given
+/// `altitude`, i.e. the inverse of [`altitude`]
+pub fn sea_level_pressure(pressure: f32, altitude: f32) -> f32 {
+    pressure / libm::powf(1.0 - altitude / 44330.0, 5.255)
+}
+
+/// Height in meters of the point at `pressure` above the point at `reference_pressure` after the
+/// hypsometric equation, given the mean `temperature` of the air between them. Unlike
+/// [`altitude`], which assumes the standard atmosphere, this accounts for the actual temperature.
+pub fn hypsometric_altitude(pressure: f32, reference_pressure: f32, temperature: f32) -> f32 {
+    R_DRY_AIR * (temperature + ZERO_CELSIUS) / GRAVITY * libm::logf(reference_pressure / pressure)
+}
+
+/// Saturation vapour pressure over water after the Magnus formula
+pub fn saturation_vapor_pressure(temperature: f32) -> f32 {
+    MAGNUS_A * libm::expf(MAGNUS_B * temperature / (MAGNUS_C + temperature))
+}
+
+/// Saturation vapour pressure over water after the Magnus formula, in double precision
+#[cfg(feature = "f64")]
+pub(crate) fn saturation_vapor_pressure_f64(temperature: f64) -> f64 {
+    let (a, b, c) = (MAGNUS_A as f64, MAGNUS_B as f64, MAGNUS_C as f64);
+    a * libm::exp(b * temperature / (c + temperature))
+}
+
+/// Partial pressure of the water vapour, from the `temperature` and the relative `humidity`
+pub fn vapor_pressure(temperature: f32, humidity: f32) -> f32 {
+    saturation_vapor_pressure(temperature) * humidity / 100.0
+}
+
+/// Dew point after the Magnus formula, from the `temperature` and the relative `humidity`.
+/// Air at 0 % holds no water vapour and has no dew point, so the result is NaN.
+///
+/// ```
+/// use bme280::derived::dew_point;
+///
+/// assert!((dew_point(20.0, 50.0) - 9.3).abs() < 0.1);
+/// assert!(dew_point(20.0, 0.0).is_nan());
+/// ```
+pub fn dew_point(temperature: f32, humidity: f32) -> f32 {
+    if humidity <= 0.0 {
+        return f32::NAN;
+    }
+    let gamma = libm::logf(humidity / 100.0) + MAGNUS_B * temperature / (MAGNUS_C + temperature);
+    MAGNUS_C * gamma / (MAGNUS_B - gamma)
+}
+
+/// Whether the dew point of the air at `temperature` and relative `humidity` comes within `margin`
+/// degrees of the `surface_temperature`, i.e. whether water is about to condense on the surface
+pub fn condensation_risk(
+    temperature: f32,
+    humidity: f32,
+    surface_temperature: f32,
+    margin: f32,
+) -> bool {
+    dew_point(temperature, humidity) >= surface_temperature - margin
+}
+
+/// Frost point after the Magnus formula over ice, from the `temperature` and the relative
+/// `humidity`, i.e. the temperature at which the water vapour deposits as frost. Like the
+/// [`dew_point`], it is NaN at 0 %.
+///
+/// ```
+/// use bme280::derived::{frost_point, saturation_vapor_pressure};
+///
+/// // the saturation vapour pressure over ice at -10 °C is 259.9 Pa
+/// let humidity = 100.0 * 259.9 / saturation_vapor_pressure(20.0);
+/// assert!((frost_point(20.0, humidity) + 10.0).abs() < 0.1);
+/// ```
+pub fn frost_point(temperature: f32, humidity: f32) -> f32 {
+    if humidity <= 0.0 {
+        return f32::NAN;
+    }
+    let gamma = libm::logf(vapor_pressure(temperature, humidity) / MAGNUS_A);
+    MAGNUS_ICE_C * gamma / (MAGNUS_ICE_B - gamma)
+}
+
+/// Absolute humidity in grams of water vapour per cubic meter, from the `temperature` and the
+/// relative `humidity`
+pub fn absolute_humidity(temperature: f32, humidity: f32) -> f32 {
+    1000.0 * vapor_pressure(temperature, humidity) / (R_VAPOR * (temperature + ZERO_CELSIUS))
+}
+
+/// Heat index after the regression of Rothfusz as used by the US National Weather Service,
+/// including its adjustments for low and high humidities, from the `temperature` and the
+/// relative `humidity`
+///
+/// ```
+/// use bme280::derived::heat_index;
+///
+/// // 106 °F at 90 °F and 70 % in the heat index chart of the NWS
+/// let heat_index = heat_index((90.0 - 32.0) / 1.8, 70.0) * 1.8 + 32.0;
+/// assert!((heat_index - 106.0).abs() < 0.5);
+/// ```
+pub fn heat_index(temperature: f32, humidity: f32) -> f32 {
+    // The regression is defined in degrees fahrenheit.
+    let t = temperature * 1.8 + 32.0;
+    let rh = humidity;
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let heat_index = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let heat_index = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            heat_index - (13.0 - rh) / 4.0 * libm::sqrtf((17.0 - libm::fabsf(t - 95.0)) / 17.0)
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            heat_index + (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0)
+        } else {
+            heat_index
+        }
+    };
+    (heat_index - 32.0) / 1.8
+}
+
+/// Density of moist air in kilograms per cubic meter, from the `temperature`, the `pressure`, and
+/// the relative `humidity`, the partial pressures of dry air and water vapour each following the
+/// ideal gas law. A `humidity` of `0.0` gives the density of dry air.
+pub fn air_density(temperature: f32, pressure: f32, humidity: f32) -> f32 {
+    let vapor_pressure = vapor_pressure(temperature, humidity);
+    let temperature = temperature + ZERO_CELSIUS;
+    (pressure - vapor_pressure) / (R_DRY_AIR * temperature)
+        + vapor_pressure / (R_VAPOR * temperature)
+}
+
+/// Density altitude in meters, i.e. the altitude of the standard atmosphere with the same air
+/// density, from the `pressure_altitude` (see [`altitude`] with [`SEA_LEVEL_PRESSURE`]) and the
+/// outside air `temperature`. The air is taken as dry, as is customary in aviation.
+///
+/// ```
+/// use bme280::derived::density_altitude;
+///
+/// // at the temperature of the standard atmosphere, it equals the pressure altitude
+/// assert!((density_altitude(1524.0, 15.0 - 6.5 * 1.524) - 1524.0).abs() < 1.0);
+/// assert!(density_altitude(1524.0, 30.0) > 2300.0);
+/// ```
+pub fn density_altitude(pressure_altitude: f32, temperature: f32) -> f32 {
+    let pressure_ratio = libm::powf(1.0 - pressure_altitude / 44330.0, 5.255);
+    let density_ratio =
+        pressure_ratio * (isa::SEA_LEVEL_TEMPERATURE + ZERO_CELSIUS) / (temperature + ZERO_CELSIUS);
+    44330.0 * (1.0 - libm::powf(density_ratio, 1.0 / 4.255))
+}
+
+/// Wet-bulb temperature after the approximation of Stull (2011), from the `temperature` and the
+/// relative `humidity`, valid from 5 % to 99 % and from -20 °C to 50 °C at standard pressure
+///
+/// ```
+/// use bme280::derived::wet_bulb_temperature;
+///
+/// // the worked example of Stull (2011)
+/// assert!((wet_bulb_temperature(20.0, 50.0) - 13.7).abs() < 0.1);
+/// ```
+pub fn wet_bulb_temperature(temperature: f32, humidity: f32) -> f32 {
+    let t = temperature;
+    let rh = humidity;
+    t * libm::atanf(0.151977 * libm::sqrtf(rh + 8.313659)) + libm::atanf(t + rh)
+        - libm::atanf(rh - 1.676331)
+        + 0.00391838 * libm::powf(rh, 1.5) * libm::atanf(0.023101 * rh)
+        - 4.686035
+}
+
+/// Humidex of the Meteorological Service of Canada, from the `temperature` and the relative
+/// `humidity`
+///
+/// ```
+/// use bme280::derived::{humidex, saturation_vapor_pressure};
+///
+/// // 34 at 30 °C with a dew point of 15 °C, after Environment Canada
+/// let humidity = 100.0 * saturation_vapor_pressure(15.0) / saturation_vapor_pressure(30.0);
+/// assert!((humidex(30.0, humidity) - 34.0).abs() < 0.5);
+/// ```
+pub fn humidex(temperature: f32, humidity: f32) -> f32 {
+    temperature + 0.5555 * (vapor_pressure(temperature, humidity) / 100.0 - 10.0)
+}
+
+/// Boiling point of water at the `pressure` after the Clausius-Clapeyron relation
+///
+/// ```
+/// use bme280::derived::{boiling_point, SEA_LEVEL_PRESSURE};
+///
+/// assert!((boiling_point(SEA_LEVEL_PRESSURE) - 100.0).abs() < 0.01);
+/// // the saturation pressure of water at 95 °C is 84.55 kPa
+/// assert!((boiling_point(84550.0) - 95.0).abs() < 0.2);
+/// ```
+pub fn boiling_point(pressure: f32) -> f32 {
+    let boiling_point_at_sea_level = 100.0 + ZERO_CELSIUS;
+    let inverse = 1.0 / boiling_point_at_sea_level
+        - R_OVER_VAPORIZATION * libm::logf(pressure / SEA_LEVEL_PRESSURE);
+    1.0 / inverse - ZERO_CELSIUS
+}
@@ -1,22 +1,64 @@
 //! BME280 driver for sensors attached via I2C.
 
-use embedded_hal::delay::DelayUs;
+#[cfg(feature = "embassy")]
+use embassy_embedded_hal::shared_bus::asynch::i2c::I2cDevice as SharedI2cDevice;
+#[cfg(feature = "embassy")]
+use embassy_sync::{blocking_mutex::raw::RawMutex, mutex::Mutex};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
 use embedded_hal::i2c::{ErrorType, I2c};
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c as AsyncI2c;
+#[cfg(feature = "async")]
+use futures_util::Stream;
 
+#[cfg(feature = "integer")]
+use super::bosch::BoschMeasurements;
+#[cfg(feature = "embedded-sensors-hal")]
+use super::sensors;
+use super::traits;
+use super::typestate::{self, Forced, Normal, Sleep, Transition};
+#[cfg(feature = "f64")]
+use super::DoubleMeasurements;
+#[cfg(feature = "with_fixed")]
+use super::FixedMeasurements;
+#[cfg(feature = "uom")]
+use super::QuantityMeasurements;
+#[cfg(feature = "async")]
+use super::{AsyncBME280Common, AsyncInterface};
 use super::{
-    BME280Common, Configuration, Error, IIRFilter, Interface, Measurements, Oversampling,
+    BME280Common, CalibrationData, ChipVariant, Configuration, Error, IIRFilter, Interface,
+    MeasurementStatistics, Measurements, Offsets, Oversampling, RawData, SensorMode,
     BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN, BME280_P_T_H_DATA_LEN,
 };
+#[cfg(feature = "integer")]
+use super::{IntegerMeasurements, MeasurementsMilli};
 
 const BME280_I2C_ADDR_PRIMARY: u8 = 0x76;
 const BME280_I2C_ADDR_SECONDARY: u8 = 0x77;
 
+#[maybe_async_cfg::maybe(
+    idents(BME280Common(sync = "BME280Common", async = "AsyncBME280Common")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
 /// Representation of a BME280
 #[derive(Debug, Default)]
 pub struct BME280<I2C> {
     common: BME280Common<I2CInterface<I2C>>,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        BME280Common(sync = "BME280Common", async = "AsyncBME280Common"),
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs"),
+        I2c(sync = "I2c", async = "AsyncI2c")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
 impl<I2C> BME280<I2C>
 where
     I2C: I2c + ErrorType,
@@ -33,105 +75,667 @@ where
 
     /// Create a new BME280 struct using a custom I²C address
     pub fn new(i2c: I2C, address: u8) -> Self {
-        BME280 {
-            common: BME280Common {
-                interface: I2CInterface { i2c, address },
-                calibration: None,
-            },
+        Self {
+            common: BME280Common::new(I2CInterface { i2c, address }),
         }
     }
 
+    /// Create a new BME280 struct whose SDO pin is driven by the MCU, e.g. to host two sensors
+    /// on one bus. The pin is driven high for the secondary address `0x77` if `secondary` is
+    /// set, and low for the primary address `0x76` otherwise.
+    ///
+    /// The pin is only borrowed to set its level, and the driver keeps using the address chosen
+    /// here. The pin must not be driven to the other level afterwards for as long as the driver
+    /// is in use: the sensor would silently move to the other address, and the driver would
+    /// fail with bus errors or, worse, talk to a second sensor answering at the old address. To
+    /// change the address, [`release`](Self::release) the bus and create the driver again.
+    pub fn new_with_sdo<P: OutputPin>(
+        i2c: I2C,
+        sdo: &mut P,
+        secondary: bool,
+    ) -> Result<Self, P::Error> {
+        if secondary {
+            sdo.set_high()?;
+            Ok(Self::new_secondary(i2c))
+        } else {
+            sdo.set_low()?;
+            Ok(Self::new_primary(i2c))
+        }
+    }
+
+    /// Releases the I²C bus, e.g. to repurpose it, or to power-gate the sensor and create the
+    /// driver again later. The sensor is left in whatever mode it is currently in.
+    pub fn release(self) -> I2C {
+        self.common.interface.i2c
+    }
+
     /// Initializes the BME280.
     /// This configures 2x temperature oversampling, 16x pressure oversampling, and the IIR filter
     /// coefficient 16.
-    pub fn init<D: DelayUs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
-        self.common.init(
-            delay,
-            Configuration::default()
-                .with_humidity_oversampling(Oversampling::Oversampling1X)
-                .with_pressure_oversampling(Oversampling::Oversampling16X)
-                .with_temperature_oversampling(Oversampling::Oversampling2X)
-                .with_iir_filter(IIRFilter::Coefficient16),
-        )
+    pub async fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.common
+            .init(
+                delay,
+                Configuration::default()
+                    .with_humidity_oversampling(Oversampling::Oversampling1X)
+                    .with_pressure_oversampling(Oversampling::Oversampling16X)
+                    .with_temperature_oversampling(Oversampling::Oversampling2X)
+                    .with_iir_filter(IIRFilter::Coefficient16),
+            )
+            .await
     }
 
     /// Initializes the BME280, applying the given configuration.
-    pub fn init_with_config<D: DelayUs>(
+    /// See [Initialization](crate#initialization).
+    pub async fn init_with_config<D: DelayNs>(
         &mut self,
         delay: &mut D,
         config: Configuration,
     ) -> Result<(), Error<I2C::Error>> {
-        self.common.init(delay, config)
+        self.common.init(delay, config).await
+    }
+
+    /// Soft-resets the BME280 and reloads its calibration data.
+    /// See [Initialization](crate#initialization).
+    pub async fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.common.reset(delay).await
+    }
+
+    /// Heals the BME280 after a bus fault or a brown-out, see [Recovery](crate#recovery).
+    pub async fn recover<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.common.recover(delay).await
+    }
+
+    /// Runs a self test of the initialized BME280, see [Self test](crate#self-test).
+    pub async fn self_test<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.common.self_test(delay).await
+    }
+
+    /// Applies a new configuration without a full re-initialization.
+    /// See [Configuration](crate#configuration).
+    pub async fn apply_config(&mut self, config: Configuration) -> Result<(), Error<I2C::Error>> {
+        self.common.apply_config(config).await
+    }
+
+    /// Changes the oversampling settings, keeping the rest of the configuration.
+    pub async fn set_oversampling(
+        &mut self,
+        temperature: Oversampling,
+        pressure: Oversampling,
+        humidity: Oversampling,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.common
+            .set_oversampling(temperature, pressure, humidity)
+            .await
+    }
+
+    /// Changes the IIR filter setting, keeping the rest of the configuration.
+    pub async fn set_iir_filter(&mut self, filter: IIRFilter) -> Result<(), Error<I2C::Error>> {
+        self.common.set_iir_filter(filter).await
+    }
+
+    /// Checks whether the BME280 still holds the configuration applied by the last `init`.
+    /// Returns `false` if the registers were reverted, e.g. by a brown-out of the sensor.
+    pub async fn verify_config(&mut self) -> Result<bool, Error<I2C::Error>> {
+        self.common.verify_config().await
+    }
+
+    /// Reads the register at `address`, for registers the rest of the API does not cover
+    pub async fn read_register(&mut self, address: u8) -> Result<u8, Error<I2C::Error>> {
+        self.common.read_register(address).await
+    }
+
+    /// Writes `value` to the register at `address`, for registers the rest of the API does not
+    /// cover. See [Configuration](crate#configuration).
+    pub async fn write_register(
+        &mut self,
+        address: u8,
+        value: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.common.write_raw_register(address, value).await
+    }
+
+    /// Enables or disables verifying the configuration before each forced measurement.
+    /// See [Recovery](crate#recovery).
+    pub fn set_config_check(&mut self, enabled: bool) {
+        self.common.check_config = enabled;
+    }
+
+    /// Enables or disables the range check of the compensated measurements.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_range_check(&mut self, enabled: bool) {
+        self.common.check_range = enabled;
+    }
+
+    /// Enables the detection of a hung sensor after `frames` identical conversions.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_stuck_detection(&mut self, frames: u8) {
+        self.common.stuck.limit = frames;
+    }
+
+    /// Returns the chip variant identified by `init`, or `None` if the chip ID has not been read
+    /// yet. An unrecognized chip ID is reported even though `init` fails for it.
+    pub fn variant(&self) -> Option<ChipVariant> {
+        self.common.variant
+    }
+
+    /// Sets offsets that are added to the measurements after compensation.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_offsets(&mut self, temperature: f32, pressure: f32, humidity: f32) {
+        self.common.offsets = Offsets {
+            temperature,
+            pressure,
+            humidity,
+            ..self.common.offsets
+        };
+    }
+
+    /// Compensates the self-heating of a sensor that reads `offset` degrees celsius too high.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_self_heating(&mut self, offset: f32) {
+        self.common.offsets.self_heating = offset;
+    }
+
+    /// Returns the calibration coefficients read by `init`, or `None` before initialization
+    pub fn calibration(&self) -> Option<&CalibrationData> {
+        self.common.calibration.as_ref()
+    }
+
+    /// Makes `init` accept the non-standard chip ID of a compatible clone part
+    pub fn expect_chip_id(&mut self, chip_id: u8, compatible: ChipVariant) {
+        self.common.chip_id_override = Some((chip_id, compatible));
     }
 
     /// Captures and processes sensor data for temperature, pressure, and humidity
-    pub fn measure<D: DelayUs>(
+    pub async fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.common.measure(delay).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity, returning
+    /// [`Error::Timeout`] if the conversion does not finish within `timeout_ms` milliseconds
+    pub async fn measure_with_timeout<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.common.measure_with_timeout(delay, timeout_ms).await
+    }
+
+    /// Captures sensor data and returns the uncompensated ADC values, e.g. to log raw frames
+    /// and compensate them later
+    pub async fn measure_raw<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RawData, Error<I2C::Error>> {
+        self.common.measure_raw(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation formulas of the
+    /// datasheet, which avoid floating point on targets without an FPU
+    #[cfg(feature = "integer")]
+    pub async fn measure_integer<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<IntegerMeasurements, Error<I2C::Error>> {
+        self.common.measure_integer(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation of the Bosch C API,
+    /// giving bit-identical results in its units
+    #[cfg(feature = "integer")]
+    pub async fn measure_bosch<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<BoschMeasurements, Error<I2C::Error>> {
+        self.common.measure_bosch(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation, in
+    /// thousandths of a degree celsius, pascals, and thousandths of a percent relative humidity
+    #[cfg(feature = "integer")]
+    pub async fn measure_milli<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<MeasurementsMilli, Error<I2C::Error>> {
+        self.common.measure_milli(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only with the 64-bit integer
+    /// compensation, in 1/256 pascals (Q24.8 format)
+    #[cfg(feature = "integer")]
+    pub async fn measure_pressure_64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<u32>, Error<I2C::Error>> {
+        self.common.measure_pressure_64(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation, returning fixed-point
+    /// numbers for code that avoids floating point
+    #[cfg(feature = "with_fixed")]
+    pub async fn measure_fixed<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<FixedMeasurements, Error<I2C::Error>> {
+        self.common.measure_fixed(delay).await
+    }
+
+    /// Captures and processes sensor data into typed quantities of the `uom` crate
+    #[cfg(feature = "uom")]
+    pub async fn measure_quantities<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<QuantityMeasurements, Error<I2C::Error>> {
+        self.common.measure_quantities(delay).await
+    }
+
+    /// Captures and processes sensor data with the double-precision floating point
+    /// compensation formulas of the datasheet
+    #[cfg(feature = "f64")]
+    pub async fn measure_f64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<DoubleMeasurements, Error<I2C::Error>> {
+        self.common.measure_f64(delay).await
+    }
+
+    /// Performs `n` forced conversions and returns the mean, minimum, and maximum of each
+    /// channel
+    pub async fn measure_n<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        n: u16,
+    ) -> Result<MeasurementStatistics<I2C::Error>, Error<I2C::Error>> {
+        self.common.measure_n(delay, n).await
+    }
+
+    /// Captures sensor data and processes the temperature only, in degrees celsius
+    pub async fn measure_temperature<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I2C::Error>> {
+        self.common.measure_temperature(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only, in pascals
+    pub async fn measure_pressure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I2C::Error>> {
+        self.common.measure_pressure(delay).await
+    }
+
+    /// Captures sensor data and processes the relative humidity only, in percent.
+    /// Returns `None` if the chip has no humidity sensor or the humidity measurement is skipped.
+    pub async fn measure_humidity<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<f32>, Error<I2C::Error>> {
+        self.common.measure_humidity(delay).await
+    }
+
+    /// Puts the BME280 into normal mode, in which it cycles between conversions and standby
+    /// periods on its own. Use [`read_latest`](Self::read_latest) to fetch the samples.
+    pub async fn set_normal_mode(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.common.normal().await
+    }
+
+    /// Reads the current operating mode back from the device.
+    pub async fn mode(&mut self) -> Result<SensorMode, Error<I2C::Error>> {
+        self.common.mode().await
+    }
+
+    /// Puts the BME280 into sleep mode.
+    /// Only the mode bits are changed, so the configuration is retained while sleeping.
+    pub async fn sleep(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.common.sleep().await
+    }
+
+    /// Wakes the BME280 from sleep mode by putting it back into normal mode.
+    /// Only the mode bits are changed, so measurements resume with the existing configuration.
+    pub async fn wake(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.common.wake().await
+    }
+
+    /// Triggers a single conversion in forced mode and returns without waiting for it.
+    /// Collect the result with [`read_triggered`](Self::read_triggered).
+    pub async fn trigger<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.common.start_conversion(delay).await
+    }
+
+    /// Waits for the conversion started with [`trigger`](Self::trigger) to finish, then reads
+    /// and processes its result.
+    pub async fn read_triggered<D: DelayNs>(
         &mut self,
         delay: &mut D,
     ) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
-        self.common.measure(delay)
+        self.common.read_triggered(delay).await
+    }
+
+    /// Returns whether a conversion is currently running, based on the status register.
+    /// This is a cheap way to check whether a triggered conversion has finished.
+    pub async fn is_measuring(&mut self) -> Result<bool, Error<I2C::Error>> {
+        self.common.is_measuring().await
+    }
+
+    /// Reads and processes the most recent sample without triggering a new conversion.
+    /// This is intended for normal mode, where the sensor keeps its data registers up to date.
+    pub async fn read_latest(&mut self) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.common.read_latest().await
+    }
+}
+
+impl<I2C> BME280<I2C>
+where
+    I2C: I2c + ErrorType,
+{
+    /// Returns an endless iterator over the samples of a BME280 running in normal mode.
+    /// See [Normal mode](crate#normal-mode).
+    pub fn samples<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> impl Iterator<Item = Result<Measurements<I2C::Error>, Error<I2C::Error>>> + 'a {
+        self.common.samples(delay)
+    }
+
+    /// Puts the BME280 into sleep mode and wraps the driver in the [`typestate`] API,
+    /// handing the driver back together with the error on failure
+    #[allow(clippy::type_complexity)]
+    pub fn into_typestate(
+        mut self,
+    ) -> Result<typestate::BME280<Self, Sleep>, (Self, Error<I2C::Error>)> {
+        match self.sleep() {
+            Ok(()) => Ok(typestate::BME280::new(self)),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Starts a forced conversion without blocking.
+    /// Poll [`read_measurement`](Self::read_measurement) to collect the result.
+    pub fn start_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.common.start_measurement(delay)
+    }
+
+    /// Returns the result of the conversion started with
+    /// [`start_measurement`](Self::start_measurement), or `nb::Error::WouldBlock` while it runs.
+    pub fn read_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> nb::Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.common.read_measurement(delay)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C> AsyncBME280<I2C>
+where
+    I2C: AsyncI2c + ErrorType,
+{
+    /// Turns the driver into an endless stream over the samples of a BME280 running in normal
+    /// mode. See [Normal mode](crate#normal-mode).
+    pub fn into_stream<D: AsyncDelayNs>(
+        self,
+        delay: D,
+    ) -> impl Stream<Item = Result<Measurements<I2C::Error>, Error<I2C::Error>>> {
+        self.common.into_stream(delay)
+    }
+}
+
+#[cfg(feature = "embassy")]
+impl<'a, M, BUS> AsyncBME280<SharedI2cDevice<'a, M, BUS>>
+where
+    M: RawMutex,
+    BUS: AsyncI2c,
+{
+    /// Create a new AsyncBME280 struct on an I²C bus shared through an `embassy_sync` mutex,
+    /// using the primary I²C address `0x76`
+    pub fn new_shared_primary(bus: &'a Mutex<M, BUS>) -> Self {
+        Self::new_primary(SharedI2cDevice::new(bus))
+    }
+
+    /// Create a new AsyncBME280 struct on an I²C bus shared through an `embassy_sync` mutex,
+    /// using the secondary I²C address `0x77`
+    pub fn new_shared_secondary(bus: &'a Mutex<M, BUS>) -> Self {
+        Self::new_secondary(SharedI2cDevice::new(bus))
+    }
+
+    /// Create a new AsyncBME280 struct on an I²C bus shared through an `embassy_sync` mutex,
+    /// using a custom I²C address
+    pub fn new_shared(bus: &'a Mutex<M, BUS>, address: u8) -> Self {
+        Self::new(SharedI2cDevice::new(bus), address)
+    }
+}
+
+impl<I2C> typestate::BME280<BME280<I2C>, Sleep>
+where
+    I2C: I2c + ErrorType,
+{
+    /// Captures and processes sensor data for temperature, pressure, and humidity
+    pub fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.device.measure(delay)
+    }
+
+    /// Triggers a single conversion in forced mode.
+    pub fn trigger<D: DelayNs>(
+        self,
+        delay: &mut D,
+    ) -> Transition<BME280<I2C>, Sleep, Forced, Error<I2C::Error>> {
+        self.transition(|device| device.trigger(delay))
+    }
+
+    /// Puts the BME280 into normal mode.
+    pub fn into_normal(self) -> Transition<BME280<I2C>, Sleep, Normal, Error<I2C::Error>> {
+        self.transition(|device| device.set_normal_mode())
     }
 }
 
-/// Register access functions for I2C
+impl<I2C> typestate::BME280<BME280<I2C>, Forced>
+where
+    I2C: I2c + ErrorType,
+{
+    /// Returns whether the triggered conversion is still running.
+    pub fn is_measuring(&mut self) -> Result<bool, Error<I2C::Error>> {
+        self.device.is_measuring()
+    }
+
+    /// Reads the result of the triggered conversion, returning `nb::Error::WouldBlock` together
+    /// with `self` while it is still running.
+    /// The sensor returns to sleep mode on its own after a forced conversion.
+    #[allow(clippy::type_complexity)]
+    pub fn read_latest(
+        mut self,
+    ) -> Result<
+        (
+            typestate::BME280<BME280<I2C>, Sleep>,
+            Measurements<I2C::Error>,
+        ),
+        (Self, nb::Error<Error<I2C::Error>>),
+    > {
+        match self.device.is_measuring() {
+            Ok(false) => {}
+            Ok(true) => return Err((self, nb::Error::WouldBlock)),
+            Err(e) => return Err((self, nb::Error::Other(e))),
+        }
+        match self.device.common.read_finished() {
+            Ok(measurements) => Ok((self.into_mode(), measurements)),
+            Err(e) => Err((self, nb::Error::Other(e))),
+        }
+    }
+}
+
+impl<I2C> typestate::BME280<BME280<I2C>, Normal>
+where
+    I2C: I2c + ErrorType,
+{
+    /// Reads and processes the most recent sample without triggering a new conversion.
+    pub fn read_latest(&mut self) -> Result<Measurements<I2C::Error>, Error<I2C::Error>> {
+        self.device.read_latest()
+    }
+
+    /// Puts the BME280 into sleep mode.
+    pub fn into_sleep(self) -> Transition<BME280<I2C>, Normal, Sleep, Error<I2C::Error>> {
+        self.transition(|device| device.sleep())
+    }
+}
+
+impl<I2C> traits::Thermometer for BME280<I2C>
+where
+    I2C: I2c + ErrorType,
+{
+    type Error = Error<I2C::Error>;
+
+    fn temperature(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_temperature(&mut delay)
+    }
+}
+
+impl<I2C> traits::Barometer for BME280<I2C>
+where
+    I2C: I2c + ErrorType,
+{
+    type Error = Error<I2C::Error>;
+
+    fn pressure(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_pressure(&mut delay)
+    }
+}
+
+impl<I2C> traits::Hygrometer for BME280<I2C>
+where
+    I2C: I2c + ErrorType,
+{
+    type Error = Error<I2C::Error>;
+
+    fn humidity(&mut self, mut delay: &mut dyn DelayNs) -> Result<Option<f32>, Self::Error> {
+        self.measure_humidity(&mut delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I2C, D> embedded_sensors_hal::sensor::ErrorType for sensors::Sensor<BME280<I2C>, D>
+where
+    I2C: I2c + ErrorType,
+{
+    type Error = Error<I2C::Error>;
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I2C, D> embedded_sensors_hal::temperature::TemperatureSensor
+    for sensors::Sensor<BME280<I2C>, D>
+where
+    I2C: I2c + ErrorType,
+    D: DelayNs,
+{
+    fn temperature(&mut self) -> Result<f32, Self::Error> {
+        self.device.measure_temperature(&mut self.delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<I2C, D> embedded_sensors_hal::humidity::RelativeHumiditySensor
+    for sensors::Sensor<BME280<I2C>, D>
+where
+    I2C: I2c + ErrorType,
+    D: DelayNs,
+{
+    fn relative_humidity(&mut self) -> Result<f32, Self::Error> {
+        self.device
+            .measure_humidity(&mut self.delay)?
+            .ok_or(Error::InvalidData)
+    }
+}
+
+/// Register access functions for I2C.
+/// This is the [`Interface`] used by [`BME280`], for use with drivers that are generic over the
+/// interface, such as [`BME680`](crate::bme680::BME680).
 #[derive(Debug, Default)]
-struct I2CInterface<I2C> {
+pub struct I2CInterface<I2C> {
     /// concrete I²C device implementation
     i2c: I2C,
     /// I²C device address
     address: u8,
 }
 
+impl<I2C> I2CInterface<I2C> {
+    /// Create a new I2CInterface for the device at the given I²C address, usually `0x76` or
+    /// `0x77` depending on the level of the SDO pin
+    pub fn new(i2c: I2C, address: u8) -> Self {
+        I2CInterface { i2c, address }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync = "Interface", async = "AsyncInterface"),
+        I2c(sync = "I2c", async = "AsyncI2c")
+    ),
+    sync(keep_self),
+    async(feature = "async", keep_self)
+)]
 impl<I2C> Interface for I2CInterface<I2C>
 where
     I2C: I2c + ErrorType,
 {
     type Error = I2C::Error;
 
-    fn read_register(&mut self, register: u8) -> Result<u8, Error<I2C::Error>> {
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<I2C::Error>> {
         let mut data: [u8; 1] = [0];
         self.i2c
             .write_read(self.address, &[register], &mut data)
+            .await
             .map_err(Error::Bus)?;
         Ok(data[0])
     }
 
-    fn read_data(
+    async fn read_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I2C::Error>> {
         let mut data = [0; BME280_P_T_H_DATA_LEN];
         self.i2c
             .write_read(self.address, &[register], &mut data)
+            .await
             .map_err(Error::Bus)?;
         Ok(data)
     }
 
-    fn read_pt_calib_data(
+    async fn read_pt_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<I2C::Error>> {
         let mut data = [0; BME280_P_T_CALIB_DATA_LEN];
         self.i2c
             .write_read(self.address, &[register], &mut data)
+            .await
             .map_err(Error::Bus)?;
         Ok(data)
     }
 
-    fn read_h_calib_data(
+    async fn read_h_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<I2C::Error>> {
         let mut data = [0; BME280_H_CALIB_DATA_LEN];
         self.i2c
             .write_read(self.address, &[register], &mut data)
+            .await
             .map_err(Error::Bus)?;
         Ok(data)
     }
 
-    fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I2C::Error>> {
+    async fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I2C::Error>> {
         self.i2c
             .write(self.address, &[register, payload])
+            .await
             .map_err(Error::Bus)
     }
 }
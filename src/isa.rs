@@ -0,0 +1,51 @@
+//! International Standard Atmosphere (ISO 2533) up to 20 km.
+//!
+//! Altitudes are geopotential, in meters above mean sea level.
+
+use super::derived::{SEA_LEVEL_PRESSURE, ZERO_CELSIUS};
+
+/// Temperature at sea level, in degrees celsius
+pub const SEA_LEVEL_TEMPERATURE: f32 = 15.0;
+
+/// Decrease of the temperature with the altitude in the troposphere, in kelvin per meter
+pub const LAPSE_RATE: f32 = 0.0065;
+
+/// Altitude of the tropopause, in meters
+pub const TROPOPAUSE: f32 = 11000.0;
+
+/// Temperature of the lower stratosphere, in degrees celsius
+pub const TROPOPAUSE_TEMPERATURE: f32 = -56.5;
+
+/// Pressure at the tropopause, in pascals
+const TROPOPAUSE_PRESSURE: f32 = 22632.06;
+
+/// Exponent of the pressure in the troposphere, g / (R · L)
+const PRESSURE_EXPONENT: f32 = 5.255877;
+
+/// Decay rate of the pressure in the lower stratosphere, g / (R · T), per meter
+const STRATOSPHERE_DECAY: f32 = 1.576_883e-4;
+
+/// Standard temperature in degrees celsius at the `altitude`
+pub fn temperature(altitude: f32) -> f32 {
+    if altitude <= TROPOPAUSE {
+        SEA_LEVEL_TEMPERATURE - LAPSE_RATE * altitude
+    } else {
+        TROPOPAUSE_TEMPERATURE
+    }
+}
+
+/// Standard pressure in pascals at the `altitude`
+pub fn pressure(altitude: f32) -> f32 {
+    if altitude <= TROPOPAUSE {
+        let ratio = (temperature(altitude) + ZERO_CELSIUS) / (SEA_LEVEL_TEMPERATURE + ZERO_CELSIUS);
+        SEA_LEVEL_PRESSURE * libm::powf(ratio, PRESSURE_EXPONENT)
+    } else {
+        TROPOPAUSE_PRESSURE * libm::expf(-STRATOSPHERE_DECAY * (altitude - TROPOPAUSE))
+    }
+}
+
+/// Deviation in degrees of the measured `temperature` at the `altitude` from the standard
+/// temperature, e.g. `ISA+10` for a `10.0`
+pub fn temperature_deviation(temperature: f32, altitude: f32) -> f32 {
+    temperature - self::temperature(altitude)
+}
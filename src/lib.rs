@@ -58,29 +58,156 @@
 //! // measure temperature, pressure, and humidity
 //! let measurements = bme280.measure().unwrap();
 //!
-//! println!("Relative Humidity = {}%", measurements.humidity);
+//! if let Some(humidity) = measurements.humidity {
+//!     println!("Relative Humidity = {}%", humidity);
+//! }
 //! println!("Temperature = {} deg C", measurements.temperature);
 //! println!("Pressure = {} pascals", measurements.pressure);
 //! ```
-
+//!
+//! ## Driver behaviour
+//!
+//! The [`i2c`], [`spi`], and [`custom`] drivers share their implementation, so the following
+//! applies to all of them, blocking and async alike.
+//!
+//! ### Initialization
+//!
+//! `init` and `init_with_config` soft-reset the chip, and only read its calibration data once
+//! the chip has finished copying it from the NVM, failing with [`Error::Timeout`] if the copy
+//! does not complete. Until `init` has succeeded, all measurements fail with
+//! [`Error::NotInitialized`] without accessing the bus.
+//!
+//! `reset` soft-resets the chip and reloads the calibration data. The configuration reverts to
+//! the power-on defaults, i.e. sleep mode with all measurements skipped, so `init_with_config` is
+//! typically called afterwards.
+//!
+//! ### Configuration
+//!
+//! `apply_config` applies a new configuration without a full re-initialization. If the sensor is
+//! running in normal mode, it is briefly put to sleep while the registers are written and resumes
+//! normal mode afterwards. Only the registers whose contents change are written. After an
+//! unnoticed reset of the sensor, re-initialize it instead, or check it with `verify_config`
+//! first, which returns `false` if the registers were reverted, e.g. by a brown-out.
+//!
+//! The configuration registers (ctrl_hum, ctrl_meas, and config) belong to the driver: after
+//! `write_register` wrote one of them, or soft-reset the chip through the reset register, the
+//! next measurement or configuration change rewrites all of them with the configuration last
+//! applied.
+//!
+//! ### Recovery
+//!
+//! The measurements are cancellation safe: if a call is dropped or fails before the conversion
+//! has been read, the next one waits for the pending conversion to finish and rewrites the
+//! configuration registers before starting a new conversion.
+//!
+//! With `set_config_check` enabled, the configuration is verified before each forced measurement,
+//! and a lost configuration is restored transparently by re-initializing the sensor. This costs
+//! three additional register reads per measurement.
+//!
+//! `recover` heals the sensor after a bus fault or a brown-out: it soft-resets the chip, waits for
+//! the calibration data to be copied from the NVM, reloads it, and re-applies the configuration
+//! last applied, leaving the sensor in sleep mode. If the recovery fails, calling it again
+//! re-applies the same configuration.
+//!
+//! ### Self test
+//!
+//! `self_test` runs the self test Bosch recommends for production lines on an initialized sensor:
+//! it verifies the chip ID, checks that the calibration data is neither all zeros nor all ones,
+//! and validates a forced conversion against the operating range of the sensor. It fails with
+//! [`Error::InvalidData`] for blank calibration data and with [`Error::OutOfRange`] for
+//! implausible results.
+//!
+//! ### Plausibility checks
+//!
+//! With `set_range_check` enabled, all measurements, including the readings of normal mode, fail
+//! with [`Error::OutOfRange`] for results outside the operating range of the sensor, see
+//! [`Measurements::in_range`]. The check applies to the compensated results, before the
+//! offsets.
+//!
+//! `set_stuck_detection` makes forced measurements fail with [`Error::SensorStuck`] once the given
+//! number of consecutive conversions returned identical raw data, until the sensor is reset.
+//! Noise makes identical frames of a working sensor very unlikely for 3 frames or more. A value
+//! of 0 disables the detection.
+//!
+//! ### Trim offsets
+//!
+//! `set_offsets` sets offsets that are added to the temperature in degrees celsius, the pressure
+//! in pascals, and the relative humidity in percent after compensation, e.g. to trim a sensor
+//! against a reference instrument.
+//!
+//! `set_self_heating` compensates the self-heating of a sensor that reads the given offset in
+//! degrees celsius above the ambient temperature, e.g. when it runs at high rates next to other
+//! components. The offset is subtracted from the temperature, and the relative humidity is
+//! rescaled to the ambient temperature. An offset of 0 disables the compensation.
+//!
+//! Both apply to all measurements, the integer ones included, which receive the offsets
+//! rounded to their resolution.
+//!
+//! ### Conversions without blocking
+//!
+//! `trigger` starts a forced conversion and returns without waiting for it, so that other work
+//! can be done meanwhile. `is_measuring` tells whether the conversion has finished, and
+//! `read_triggered` waits for whatever remains of it and returns its result. For `nb`-style
+//! polling, `start_measurement` starts a conversion, and `read_measurement` returns
+//! `nb::Error::WouldBlock` while it is running, starting one if none is pending. Like `measure`,
+//! `trigger` and `start_measurement` first recover from an interrupted sequence and restore a
+//! lost configuration if the check is enabled, which are the only steps using the delay.
+//!
+//! ### Normal mode
+//!
+//! In normal mode, entered with `set_normal_mode`, the sensor cycles between conversions and
+//! standby periods on its own. `read_latest` reads and processes the most recent sample.
+//! `samples`, or `into_stream` for the async drivers, yields the samples, waiting one output
+//! period between them, derived from the configured oversampling and standby time. `sleep` and
+//! `wake` only change the mode bits, so the configuration is retained.
+
+pub mod altimeter;
+pub mod average;
+pub mod bme680;
+#[cfg(feature = "integer")]
+pub mod bosch;
+pub mod comfort;
+#[cfg(feature = "with_std")]
+pub mod csv;
+pub mod custom;
+pub mod derived;
 pub mod i2c;
+pub mod isa;
+pub mod psychrometrics;
+pub mod retry;
+#[cfg(feature = "embedded-sensors-hal")]
+pub mod sensors;
 pub mod spi;
+pub mod tendency;
+pub mod test_vectors;
+pub mod traits;
+pub mod typestate;
+pub mod units;
+pub mod variometer;
+pub mod zambretti;
 
 use core::marker::PhantomData;
-use embedded_hal::delay::DelayUs;
-
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+#[cfg(feature = "async")]
+use futures_util::stream::{self, Stream};
+
+#[cfg(feature = "with_fixed")]
+use fixed::types::{I16F16, U22F10, U24F8};
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "with_defmt")]
 use defmt::{write, Format, Formatter};
 
-#[cfg(feature = "with_std")]
-use derive_more::Display;
-#[cfg(feature = "with_std")]
-use std::error;
-#[cfg(feature = "with_std")]
-use std::fmt;
+#[cfg(feature = "uom")]
+use uom::si::f32::{Pressure, Ratio, ThermodynamicTemperature};
+#[cfg(feature = "uom")]
+use uom::si::{pressure::pascal, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use core::convert::TryInto;
+use core::fmt;
 
 const BME280_PWR_CTRL_ADDR: u8 = 0xF4;
 const BME280_CTRL_HUM_ADDR: u8 = 0xF2;
@@ -94,18 +221,51 @@ const BME280_CHIP_ID: u8 = 0x60;
 const BMP280_CHIP_ID: u8 = 0x58;
 const BME280_CHIP_ID_ADDR: u8 = 0xD0;
 
+const BME280_STATUS_ADDR: u8 = 0xF3;
+const BME280_STATUS_MEASURING_MSK: u8 = 0x08;
+const BME280_STATUS_IM_UPDATE_MSK: u8 = 0x01;
+
 const BME280_DATA_ADDR: u8 = 0xF7;
-const BME280_P_T_H_DATA_LEN: usize = 8;
+/// Length of the burst read of the pressure, temperature, and humidity data registers
+pub const BME280_P_T_H_DATA_LEN: usize = 8;
+
+// worst-case conversion time is 112.8ms with 16x oversampling on all channels
+const BME280_MEASUREMENT_TIMEOUT_MS: u32 = 125;
+
+// the NVM copy after a reset takes well below a millisecond
+const BME280_NVM_COPY_TIMEOUT_MS: u32 = 10;
+
+// number of additional burst reads when checking for a torn read
+const BME280_TORN_READ_RETRIES: usize = 3;
+
+#[cfg(feature = "pressure")]
+const BME280_PRESSURE_SKIPPED: u32 = 0x80000;
+const BME280_TEMPERATURE_SKIPPED: u32 = 0x80000;
+const BME280_HUMIDITY_SKIPPED: u32 = 0x8000;
 
 const BME280_P_T_CALIB_DATA_ADDR: u8 = 0x88;
-const BME280_P_T_CALIB_DATA_LEN: usize = 26;
+/// Length of the burst read of the pressure and temperature calibration data
+pub const BME280_P_T_CALIB_DATA_LEN: usize = 26;
 
 const BME280_H_CALIB_DATA_ADDR: u8 = 0xE1;
-const BME280_H_CALIB_DATA_LEN: usize = 7;
+/// Length of the burst read of the humidity calibration data
+pub const BME280_H_CALIB_DATA_LEN: usize = 7;
+/// Length of the serialized calibration data, see [`CalibrationData::to_bytes`]
+pub const BME280_CALIB_DATA_LEN: usize = BME280_P_T_CALIB_DATA_LEN + BME280_H_CALIB_DATA_LEN;
 
 const BME280_TEMP_MIN: f32 = -40.0;
 const BME280_TEMP_MAX: f32 = 85.0;
 
+// limits of the integer compensation, in hundredths of a degree celsius and pascals
+#[cfg(feature = "integer")]
+const BME280_TEMP_MIN_INT: i32 = -4000;
+#[cfg(feature = "integer")]
+const BME280_TEMP_MAX_INT: i32 = 8500;
+#[cfg(all(feature = "integer", feature = "pressure"))]
+const BME280_PRESSURE_MIN_INT: u32 = 30000;
+#[cfg(all(feature = "integer", feature = "pressure"))]
+const BME280_PRESSURE_MAX_INT: u32 = 110000;
+
 const BME280_PRESSURE_MIN: f32 = 30000.0;
 const BME280_PRESSURE_MAX: f32 = 110000.0;
 
@@ -114,6 +274,8 @@ const BME280_HUMIDITY_MAX: f32 = 100.0;
 
 const BME280_SLEEP_MODE: u8 = 0x00;
 const BME280_FORCED_MODE: u8 = 0x01;
+/// the mode bits `0b10` select forced mode as well
+const BME280_FORCED_MODE_ALT: u8 = 0x02;
 const BME280_NORMAL_MODE: u8 = 0x03;
 
 const BME280_SENSOR_MODE_MSK: u8 = 0x03;
@@ -134,6 +296,20 @@ const BME280_FILTER_COEFF_4: u8 = 0x02;
 const BME280_FILTER_COEFF_8: u8 = 0x03;
 const BME280_FILTER_COEFF_16: u8 = 0x04;
 
+const BME280_STANDBY_MSK: u8 = 0xE0;
+const BME280_STANDBY_POS: u8 = 0x05;
+const BME280_STANDBY_TIME_0_5_MS: u8 = 0x00;
+const BME280_STANDBY_TIME_62_5_MS: u8 = 0x01;
+const BME280_STANDBY_TIME_125_MS: u8 = 0x02;
+const BME280_STANDBY_TIME_250_MS: u8 = 0x03;
+const BME280_STANDBY_TIME_500_MS: u8 = 0x04;
+const BME280_STANDBY_TIME_1000_MS: u8 = 0x05;
+const BME280_STANDBY_TIME_10_MS: u8 = 0x06;
+const BME280_STANDBY_TIME_20_MS: u8 = 0x07;
+
+const BME280_SPI3W_EN_MSK: u8 = 0x01;
+
+const BME280_OVERSAMPLING_SKIP: u8 = 0x00;
 const BME280_OVERSAMPLING_1X: u8 = 0x01;
 const BME280_OVERSAMPLING_2X: u8 = 0x02;
 const BME280_OVERSAMPLING_4X: u8 = 0x03;
@@ -153,21 +329,51 @@ macro_rules! set_bits {
 }
 
 /// BME280 errors
-#[cfg_attr(feature = "with_std", derive(Display))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error<E> {
     /// Failed to compensate a raw measurement
     CompensationFailed,
-    /// I²C or SPI bus error
+    /// Bus error returned by an [`Interface`] implementation. The drivers attach the register
+    /// access that failed, returning [`Error::BusOperation`] instead.
     Bus(E),
+    /// I²C or SPI bus error during the given operation of the driver
+    BusOperation {
+        /// register access that failed
+        operation: Operation,
+        /// error of the bus
+        error: E,
+    },
     /// Failed to parse sensor data
     InvalidData,
-    /// No calibration data is available (probably forgot to call or check BME280::init for failure)
+    /// No calibration data is available. Superseded by [`Error::NotInitialized`] and no longer
+    /// returned by the drivers.
     NoCalibrationData,
-    /// Chip ID doesn't match expected value
+    /// The driver has not been initialized: `init` was not called, or it failed before the
+    /// calibration data was read. Returned before any conversion is started.
+    NotInitialized,
+    /// Chip ID doesn't match expected value. Superseded by [`Error::UnexpectedChipId`] and no
+    /// longer returned by the drivers.
     UnsupportedChip,
+    /// The chip ID read during initialization is not one of a supported chip. `0x00` or `0xFF`
+    /// usually point to wiring problems, e.g. a floating or shorted data line.
+    UnexpectedChipId {
+        /// chip ID of the supported chip, the BMP280 (`0x58`) being accepted too by the BME280
+        /// driver
+        expected: u8,
+        /// chip ID read from the device
+        found: u8,
+    },
     /// Delay error
     Delay,
+    /// The sensor did not finish a conversion in time
+    Timeout,
+    /// A result lies outside the operating range of the sensor, which points to a wiring fault
+    /// or corrupted calibration data. Only returned with the range check enabled.
+    OutOfRange,
+    /// Consecutive conversions returned identical raw data, a symptom of a hung sensor that
+    /// needs to be power-cycled. Only returned with the stuck detection enabled.
+    SensorStuck,
 }
 
 #[cfg(feature = "with_defmt")]
@@ -176,19 +382,187 @@ impl<E> Format for Error<E> {
         match self {
             Error::CompensationFailed => write!(fmt, "Compensation failure"),
             Error::Bus(_) => write!(fmt, "Bus error"),
+            Error::BusOperation { operation, .. } => write!(fmt, "Bus error while {}", operation),
             Error::InvalidData => write!(fmt, "Invalid data"),
             Error::NoCalibrationData => write!(fmt, "No calibration data"),
+            Error::NotInitialized => write!(fmt, "Not initialized"),
             Error::UnsupportedChip => write!(fmt, "Unsupported chip"),
+            Error::UnexpectedChipId { expected, found } => write!(
+                fmt,
+                "Unexpected chip ID {=u8:#04x}, expected {=u8:#04x}",
+                found, expected
+            ),
             Error::Delay => write!(fmt, "Delay issue"),
+            Error::Timeout => write!(fmt, "Timeout"),
+            Error::OutOfRange => write!(fmt, "Out of range"),
+            Error::SensorStuck => write!(fmt, "Sensor stuck"),
         }
     }
 }
 
-#[cfg(feature = "with_std")]
-impl<T: fmt::Debug + fmt::Display> error::Error for Error<T> {}
+impl<E> Error<E> {
+    /// Attaches the `operation` to a bus error
+    fn during(self, operation: Operation) -> Self {
+        match self {
+            Error::Bus(error) => Error::BusOperation { operation, error },
+            e => e,
+        }
+    }
+
+    /// Converts the bus error with `f`, leaving the other variants as they are, e.g. to unify the
+    /// errors of sensors on different buses into a single application error type:
+    ///
+    /// ```
+    /// use bme280::Error;
+    ///
+    /// #[derive(Debug)]
+    /// struct BusFault;
+    ///
+    /// let error: Error<u8> = Error::Bus(0x2A);
+    /// assert!(matches!(error.map_bus(|_| BusFault), Error::Bus(BusFault)));
+    /// ```
+    pub fn map_bus<F>(self, f: impl FnOnce(E) -> F) -> Error<F> {
+        match self {
+            Error::CompensationFailed => Error::CompensationFailed,
+            Error::Bus(error) => Error::Bus(f(error)),
+            Error::BusOperation { operation, error } => Error::BusOperation {
+                operation,
+                error: f(error),
+            },
+            Error::InvalidData => Error::InvalidData,
+            Error::NoCalibrationData => Error::NoCalibrationData,
+            Error::NotInitialized => Error::NotInitialized,
+            Error::UnsupportedChip => Error::UnsupportedChip,
+            Error::UnexpectedChipId { expected, found } => {
+                Error::UnexpectedChipId { expected, found }
+            }
+            Error::Delay => Error::Delay,
+            Error::Timeout => Error::Timeout,
+            Error::OutOfRange => Error::OutOfRange,
+            Error::SensorStuck => Error::SensorStuck,
+        }
+    }
+}
+
+/// Register access of the driver, carried by [`Error::BusOperation`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// reading from the register at the address
+    Read(u8),
+    /// writing to the register at the address
+    Write(u8),
+}
+
+impl Operation {
+    /// Name of the register accessed, after the datasheet
+    pub fn register_name(&self) -> &'static str {
+        let (Operation::Read(register) | Operation::Write(register)) = *self;
+        match register {
+            BME280_P_T_CALIB_DATA_ADDR | BME280_H_CALIB_DATA_ADDR => "calibration data",
+            BME280_CHIP_ID_ADDR => "chip_id",
+            BME280_RESET_ADDR => "reset",
+            BME280_CTRL_HUM_ADDR => "ctrl_hum",
+            BME280_STATUS_ADDR => "status",
+            BME280_CTRL_MEAS_ADDR => "ctrl_meas",
+            BME280_CONFIG_ADDR => "config",
+            BME280_DATA_ADDR => "data",
+            _ => "register",
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Read(register) => {
+                core::write!(f, "reading {} at 0x{:02X}", self.register_name(), register)
+            }
+            Operation::Write(register) => {
+                core::write!(f, "writing {} at 0x{:02X}", self.register_name(), register)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with_defmt")]
+impl Format for Operation {
+    fn format(&self, fmt: Formatter) {
+        match self {
+            Operation::Read(register) => {
+                write!(
+                    fmt,
+                    "reading {} at {=u8:#04x}",
+                    self.register_name(),
+                    register
+                )
+            }
+            Operation::Write(register) => {
+                write!(
+                    fmt,
+                    "writing {} at {=u8:#04x}",
+                    self.register_name(),
+                    register
+                )
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::CompensationFailed => f.write_str("Compensation failure"),
+            Error::Bus(e) => core::write!(f, "Bus error: {:?}", e),
+            Error::BusOperation { operation, error } => {
+                core::write!(f, "Bus error while {}: {:?}", operation, error)
+            }
+            Error::InvalidData => f.write_str("Invalid data"),
+            Error::NoCalibrationData => f.write_str("No calibration data"),
+            Error::NotInitialized => f.write_str("Not initialized"),
+            Error::UnsupportedChip => f.write_str("Unsupported chip"),
+            Error::UnexpectedChipId { expected, found } => core::write!(
+                f,
+                "Unexpected chip ID 0x{:02X}, expected 0x{:02X}",
+                found,
+                expected
+            ),
+            Error::Delay => f.write_str("Delay issue"),
+            Error::Timeout => f.write_str("Timeout"),
+            Error::OutOfRange => f.write_str("Out of range"),
+            Error::SensorStuck => f.write_str("Sensor stuck"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for Error<E> {}
+
+/// Chip variant, identified by the chip ID read during initialization
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChipVariant {
+    /// BME280, measuring temperature, pressure, and humidity
+    Bme280,
+    /// BMP280, measuring temperature and pressure
+    Bmp280,
+    /// Unrecognized chip ID
+    Unknown(u8),
+}
+
+impl ChipVariant {
+    fn from_chip_id(chip_id: u8) -> Self {
+        match chip_id {
+            BME280_CHIP_ID => ChipVariant::Bme280,
+            BMP280_CHIP_ID => ChipVariant::Bmp280,
+            _ => ChipVariant::Unknown(chip_id),
+        }
+    }
+}
 
 /// BME280 operating mode
-#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SensorMode {
     /// Sleep mode
     Sleep,
@@ -201,8 +575,16 @@ pub enum SensorMode {
 /// Oversampling settings for temperature, pressure, and humidity measurements.
 /// See sections 3.4ff of the manual for measurement flow and recommended values.
 /// The default is 1x, i.e., no oversampling.
-#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_defmt", derive(Format))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Oversampling {
+    /// Skips the measurement of the channel.
+    /// Skipped temperature and pressure channels are reported as NaN, a skipped humidity channel
+    /// as `None`. Pressure and humidity compensation depend on the temperature, so skipping the
+    /// temperature measurement also invalidates the other channels.
+    Skip,
     /// Disables oversampling.
     /// Without IIR filtering, this sets the resolution of temperature and pressure measurements
     /// to 16 bits.
@@ -228,6 +610,7 @@ pub enum Oversampling {
 impl Oversampling {
     fn bits(&self) -> u8 {
         match self {
+            Oversampling::Skip => BME280_OVERSAMPLING_SKIP,
             Oversampling::Oversampling1X => BME280_OVERSAMPLING_1X,
             Oversampling::Oversampling2X => BME280_OVERSAMPLING_2X,
             Oversampling::Oversampling4X => BME280_OVERSAMPLING_4X,
@@ -235,6 +618,31 @@ impl Oversampling {
             Oversampling::Oversampling16X => BME280_OVERSAMPLING_16X,
         }
     }
+
+    /// Oversampling setting with the given number of samples, `0` skipping the measurement
+    pub fn from_factor(factor: u32) -> Option<Self> {
+        match factor {
+            0 => Some(Oversampling::Skip),
+            1 => Some(Oversampling::Oversampling1X),
+            2 => Some(Oversampling::Oversampling2X),
+            4 => Some(Oversampling::Oversampling4X),
+            8 => Some(Oversampling::Oversampling8X),
+            16 => Some(Oversampling::Oversampling16X),
+            _ => None,
+        }
+    }
+
+    /// Number of samples averaged per measurement, `0` if the measurement is skipped
+    pub fn factor(&self) -> u32 {
+        match self {
+            Oversampling::Skip => 0,
+            Oversampling::Oversampling1X => 1,
+            Oversampling::Oversampling2X => 2,
+            Oversampling::Oversampling4X => 4,
+            Oversampling::Oversampling8X => 8,
+            Oversampling::Oversampling16X => 16,
+        }
+    }
 }
 
 impl Default for Oversampling {
@@ -246,7 +654,10 @@ impl Default for Oversampling {
 /// Lowpass filter settings for pressure and temperature values.
 /// See section 3.4.4 of the datasheet for more information on this.
 /// The default setting is disabled.
-#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_defmt", derive(Format))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum IIRFilter {
     /// Disables the IIR filter.
     /// The resolution of pressure and temperature measurements is dictated by their respective
@@ -278,6 +689,29 @@ impl IIRFilter {
             IIRFilter::Coefficient16 => BME280_FILTER_COEFF_16,
         }
     }
+
+    /// Filter setting with the given coefficient, `0` disabling the filter
+    pub fn from_coefficient(coefficient: u8) -> Option<Self> {
+        match coefficient {
+            0 => Some(IIRFilter::Off),
+            2 => Some(IIRFilter::Coefficient2),
+            4 => Some(IIRFilter::Coefficient4),
+            8 => Some(IIRFilter::Coefficient8),
+            16 => Some(IIRFilter::Coefficient16),
+            _ => None,
+        }
+    }
+
+    /// Filter coefficient, `0` if the filter is off
+    pub fn coefficient(&self) -> u8 {
+        match self {
+            IIRFilter::Off => 0,
+            IIRFilter::Coefficient2 => 2,
+            IIRFilter::Coefficient4 => 4,
+            IIRFilter::Coefficient8 => 8,
+            IIRFilter::Coefficient16 => 16,
+        }
+    }
 }
 
 impl Default for IIRFilter {
@@ -286,17 +720,174 @@ impl Default for IIRFilter {
     }
 }
 
+/// Inactive duration between two measurements in normal mode.
+/// Together with the measurement time this determines the output data rate.
+/// See section 3.3.4 of the datasheet for more information.
+/// The default is 0.5ms.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_defmt", derive(Format))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StandbyTime {
+    /// 0.5ms standby time
+    #[default]
+    Millis0_5,
+    /// 10ms standby time
+    Millis10,
+    /// 20ms standby time
+    Millis20,
+    /// 62.5ms standby time
+    Millis62_5,
+    /// 125ms standby time
+    Millis125,
+    /// 250ms standby time
+    Millis250,
+    /// 500ms standby time
+    Millis500,
+    /// 1000ms standby time
+    Millis1000,
+}
+
+impl StandbyTime {
+    fn bits(&self) -> u8 {
+        match self {
+            StandbyTime::Millis0_5 => BME280_STANDBY_TIME_0_5_MS,
+            StandbyTime::Millis10 => BME280_STANDBY_TIME_10_MS,
+            StandbyTime::Millis20 => BME280_STANDBY_TIME_20_MS,
+            StandbyTime::Millis62_5 => BME280_STANDBY_TIME_62_5_MS,
+            StandbyTime::Millis125 => BME280_STANDBY_TIME_125_MS,
+            StandbyTime::Millis250 => BME280_STANDBY_TIME_250_MS,
+            StandbyTime::Millis500 => BME280_STANDBY_TIME_500_MS,
+            StandbyTime::Millis1000 => BME280_STANDBY_TIME_1000_MS,
+        }
+    }
+
+    /// Standby time of the given duration in microseconds
+    pub fn from_micros(micros: u32) -> Option<Self> {
+        match micros {
+            500 => Some(StandbyTime::Millis0_5),
+            10_000 => Some(StandbyTime::Millis10),
+            20_000 => Some(StandbyTime::Millis20),
+            62_500 => Some(StandbyTime::Millis62_5),
+            125_000 => Some(StandbyTime::Millis125),
+            250_000 => Some(StandbyTime::Millis250),
+            500_000 => Some(StandbyTime::Millis500),
+            1_000_000 => Some(StandbyTime::Millis1000),
+            _ => None,
+        }
+    }
+
+    /// Duration of the standby time in microseconds
+    pub fn micros(&self) -> u32 {
+        match self {
+            StandbyTime::Millis0_5 => 500,
+            StandbyTime::Millis10 => 10_000,
+            StandbyTime::Millis20 => 20_000,
+            StandbyTime::Millis62_5 => 62_500,
+            StandbyTime::Millis125 => 125_000,
+            StandbyTime::Millis250 => 250_000,
+            StandbyTime::Millis500 => 500_000,
+            StandbyTime::Millis1000 => 1_000_000,
+        }
+    }
+}
+
+/// Duration of a single conversion, in microseconds
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MeasurementDuration {
+    /// typical conversion time
+    pub typical_us: u32,
+    /// maximum conversion time
+    pub max_us: u32,
+}
+
 /// Configuration values for the BME280 sensor.
-/// The default sets all oversampling settings to 1x and disables the IIR filter.
-#[derive(Debug, Copy, Clone, Default)]
+/// The default sets all oversampling settings to 1x, disables the IIR filter, and uses the
+/// shortest standby time.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_defmt", derive(Format))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Configuration {
     temperature_oversampling: Oversampling,
     pressure_oversampling: Oversampling,
     humidity_oversampling: Oversampling,
     iir_filter: IIRFilter,
+    standby_time: StandbyTime,
 }
 
 impl Configuration {
+    /// Register contents after a power-on or soft reset: all channels skipped
+    fn reset_state() -> Self {
+        Configuration {
+            temperature_oversampling: Oversampling::Skip,
+            pressure_oversampling: Oversampling::Skip,
+            humidity_oversampling: Oversampling::Skip,
+            iir_filter: IIRFilter::Off,
+            standby_time: StandbyTime::Millis0_5,
+        }
+    }
+
+    /// Recommended settings for weather monitoring, see section 3.5.1 of the datasheet.
+    /// Intended for forced mode at one measurement per minute.
+    pub fn weather_monitoring() -> Self {
+        Configuration::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Oversampling1X)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Off)
+    }
+
+    /// Recommended settings for humidity sensing, see section 3.5.2 of the datasheet.
+    /// Intended for forced mode at one measurement per second. Pressure is skipped.
+    pub fn humidity_sensing() -> Self {
+        Configuration::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Skip)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Off)
+    }
+
+    /// Recommended settings for indoor navigation, see section 3.5.3 of the datasheet.
+    /// Intended for normal mode.
+    pub fn indoor_navigation() -> Self {
+        Configuration::default()
+            .with_temperature_oversampling(Oversampling::Oversampling2X)
+            .with_pressure_oversampling(Oversampling::Oversampling16X)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Coefficient16)
+            .with_standby_time(StandbyTime::Millis0_5)
+    }
+
+    /// Recommended settings for gaming, see section 3.5.4 of the datasheet.
+    /// Intended for normal mode. Humidity is skipped.
+    pub fn gaming() -> Self {
+        Configuration::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Oversampling4X)
+            .with_humidity_oversampling(Oversampling::Skip)
+            .with_iir_filter(IIRFilter::Coefficient16)
+            .with_standby_time(StandbyTime::Millis0_5)
+    }
+
+    /// Computes the typical and maximum time a conversion takes with these oversampling
+    /// settings, following section 9.1 of the datasheet.
+    pub fn measurement_duration(&self) -> MeasurementDuration {
+        let t = self.temperature_oversampling.factor();
+        let p = self.pressure_oversampling.factor();
+        let h = self.humidity_oversampling.factor();
+        // pressure and humidity conversions carry an extra setup time when enabled
+        let setup = |os: u32, us: u32| if os == 0 { 0 } else { us };
+
+        let typical_us = 1000 + 2000 * t + 2000 * p + setup(p, 500) + 2000 * h + setup(h, 500);
+        let max_us = 1250 + 2300 * t + 2300 * p + setup(p, 575) + 2300 * h + setup(h, 575);
+        MeasurementDuration { typical_us, max_us }
+    }
+
+    /// Worst-case time between two samples in normal mode
+    fn output_period_us(&self) -> u32 {
+        self.measurement_duration().max_us + self.standby_time.micros()
+    }
+
     /// Sets the temperature oversampling setting.
     pub fn with_temperature_oversampling(mut self, oversampling: Oversampling) -> Self {
         self.temperature_oversampling = oversampling;
@@ -320,50 +911,240 @@ impl Configuration {
         self.iir_filter = filter;
         self
     }
+
+    /// Sets the standby time used between measurements in normal mode.
+    pub fn with_standby_time(mut self, standby_time: StandbyTime) -> Self {
+        self.standby_time = standby_time;
+        self
+    }
 }
 
-#[derive(Debug)]
-struct CalibrationData {
+/// Calibration coefficients read from the non-volatile memory of a sensor
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone)]
+pub struct CalibrationData {
     dig_t1: u16,
     dig_t2: i16,
     dig_t3: i16,
+    #[cfg(feature = "pressure")]
     dig_p1: u16,
+    #[cfg(feature = "pressure")]
     dig_p2: i16,
+    #[cfg(feature = "pressure")]
     dig_p3: i16,
+    #[cfg(feature = "pressure")]
     dig_p4: i16,
+    #[cfg(feature = "pressure")]
     dig_p5: i16,
+    #[cfg(feature = "pressure")]
     dig_p6: i16,
+    #[cfg(feature = "pressure")]
     dig_p7: i16,
+    #[cfg(feature = "pressure")]
     dig_p8: i16,
+    #[cfg(feature = "pressure")]
     dig_p9: i16,
+    #[cfg(feature = "humidity")]
     dig_h1: u8,
+    #[cfg(feature = "humidity")]
     dig_h2: i16,
+    #[cfg(feature = "humidity")]
     dig_h3: u8,
+    #[cfg(feature = "humidity")]
     dig_h4: i16,
+    #[cfg(feature = "humidity")]
     dig_h5: i16,
+    #[cfg(feature = "humidity")]
     dig_h6: i8,
     t_fine: i32,
 }
 
-/// Measurement data
-#[cfg_attr(feature = "serde", derive(Serialize))]
-#[derive(Debug)]
-pub struct Measurements<E> {
-    /// temperature in degrees celsius
-    pub temperature: f32,
-    /// pressure in pascals
-    pub pressure: f32,
-    /// percent relative humidity (`0` with BMP280)
-    pub humidity: f32,
-    #[cfg_attr(feature = "serde", serde(skip))]
-    _e: PhantomData<E>,
+impl CalibrationData {
+    /// Serializes the coefficients in the layout of the calibration registers, i.e. the
+    /// pressure and temperature calibration data followed by the humidity calibration data.
+    /// Reserved bits and coefficients compiled out with the `humidity` or `pressure` features
+    /// are zero.
+    pub fn to_bytes(&self) -> [u8; BME280_CALIB_DATA_LEN] {
+        let mut bytes = [0; BME280_CALIB_DATA_LEN];
+        bytes[0..2].copy_from_slice(&self.dig_t1.to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.dig_t2.to_le_bytes());
+        bytes[4..6].copy_from_slice(&self.dig_t3.to_le_bytes());
+        #[cfg(feature = "pressure")]
+        {
+            let dig_p = [
+                self.dig_p2,
+                self.dig_p3,
+                self.dig_p4,
+                self.dig_p5,
+                self.dig_p6,
+                self.dig_p7,
+                self.dig_p8,
+                self.dig_p9,
+            ];
+            bytes[6..8].copy_from_slice(&self.dig_p1.to_le_bytes());
+            for (i, coefficient) in dig_p.iter().enumerate() {
+                bytes[8 + 2 * i..10 + 2 * i].copy_from_slice(&coefficient.to_le_bytes());
+            }
+        }
+        #[cfg(feature = "humidity")]
+        {
+            bytes[25] = self.dig_h1;
+            let h = &mut bytes[BME280_P_T_CALIB_DATA_LEN..];
+            h[0..2].copy_from_slice(&self.dig_h2.to_le_bytes());
+            h[2] = self.dig_h3;
+            h[3] = (self.dig_h4 >> 4) as u8;
+            h[4] = (self.dig_h4 & 0x0F) as u8 | ((self.dig_h5 & 0x0F) << 4) as u8;
+            h[5] = (self.dig_h5 >> 4) as u8;
+            h[6] = self.dig_h6 as u8;
+        }
+        bytes
+    }
+
+    /// Parses coefficients serialized with [`to_bytes`](Self::to_bytes), or a dump of the
+    /// calibration registers in the same layout
+    pub fn from_bytes(bytes: &[u8; BME280_CALIB_DATA_LEN]) -> Self {
+        let mut pt_data = [0; BME280_P_T_CALIB_DATA_LEN];
+        let mut h_data = [0; BME280_H_CALIB_DATA_LEN];
+        pt_data.copy_from_slice(&bytes[..BME280_P_T_CALIB_DATA_LEN]);
+        h_data.copy_from_slice(&bytes[BME280_P_T_CALIB_DATA_LEN..]);
+        parse_calib_data(&pt_data, &h_data)
+    }
+
+    /// Parses coefficients from a buffer, e.g. one read back from external storage, returning
+    /// `None` unless it holds exactly [`BME280_CALIB_DATA_LEN`] bytes
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; BME280_CALIB_DATA_LEN] = bytes.try_into().ok()?;
+        Some(Self::from_bytes(bytes))
+    }
+
+    /// The intermediate `t_fine` value of the last compensated measurement, i.e. the temperature
+    /// in 1/5120 degrees celsius that the pressure and humidity compensation are based on.
+    /// It is zero until a measurement has been compensated.
+    pub fn t_fine(&self) -> i32 {
+        self.t_fine
+    }
+
+    /// The `dig_T1` coefficient
+    pub fn dig_t1(&self) -> u16 {
+        self.dig_t1
+    }
+
+    /// The `dig_T2` coefficient
+    pub fn dig_t2(&self) -> i16 {
+        self.dig_t2
+    }
+
+    /// The `dig_T3` coefficient
+    pub fn dig_t3(&self) -> i16 {
+        self.dig_t3
+    }
+
+    /// The `dig_P1` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p1(&self) -> u16 {
+        self.dig_p1
+    }
+
+    /// The `dig_P2` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p2(&self) -> i16 {
+        self.dig_p2
+    }
+
+    /// The `dig_P3` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p3(&self) -> i16 {
+        self.dig_p3
+    }
+
+    /// The `dig_P4` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p4(&self) -> i16 {
+        self.dig_p4
+    }
+
+    /// The `dig_P5` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p5(&self) -> i16 {
+        self.dig_p5
+    }
+
+    /// The `dig_P6` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p6(&self) -> i16 {
+        self.dig_p6
+    }
+
+    /// The `dig_P7` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p7(&self) -> i16 {
+        self.dig_p7
+    }
+
+    /// The `dig_P8` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p8(&self) -> i16 {
+        self.dig_p8
+    }
+
+    /// The `dig_P9` coefficient
+    #[cfg(feature = "pressure")]
+    pub fn dig_p9(&self) -> i16 {
+        self.dig_p9
+    }
+
+    /// The `dig_H1` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h1(&self) -> u8 {
+        self.dig_h1
+    }
+
+    /// The `dig_H2` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h2(&self) -> i16 {
+        self.dig_h2
+    }
+
+    /// The `dig_H3` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h3(&self) -> u8 {
+        self.dig_h3
+    }
+
+    /// The `dig_H4` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h4(&self) -> i16 {
+        self.dig_h4
+    }
+
+    /// The `dig_H5` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h5(&self) -> i16 {
+        self.dig_h5
+    }
+
+    /// The `dig_H6` coefficient
+    #[cfg(feature = "humidity")]
+    pub fn dig_h6(&self) -> i8 {
+        self.dig_h6
+    }
 }
 
-impl<E> Measurements<E> {
-    fn parse(
-        data: [u8; BME280_P_T_H_DATA_LEN],
-        calibration: &mut CalibrationData,
-    ) -> Result<Self, Error<E>> {
+/// Uncompensated ADC values of a measurement.
+/// Skipped channels read `0x80000` for temperature and pressure, and `0x8000` for humidity.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RawData {
+    /// 20-bit pressure ADC value
+    pub pressure: u32,
+    /// 20-bit temperature ADC value
+    pub temperature: u32,
+    /// 16-bit humidity ADC value
+    pub humidity: u32,
+}
+
+impl RawData {
+    fn parse(data: [u8; BME280_P_T_H_DATA_LEN]) -> Self {
         let data_msb = (data[0] as u32) << 12;
         let data_lsb = (data[1] as u32) << 4;
         let data_xlsb = (data[2] as u32) >> 4;
@@ -378,54 +1159,455 @@ impl<E> Measurements<E> {
         let data_lsb = data[7] as u32;
         let humidity = data_msb | data_lsb;
 
-        let temperature = Measurements::compensate_temperature(temperature, calibration)?;
-        let pressure = Measurements::compensate_pressure(pressure, calibration)?;
-        let humidity = Measurements::compensate_humidity(humidity, calibration)?;
-
-        Ok(Measurements {
-            temperature,
+        RawData {
             pressure,
+            temperature,
             humidity,
-            _e: PhantomData,
-        })
+        }
     }
+}
 
-    fn compensate_temperature(
-        uncompensated: u32,
-        calibration: &mut CalibrationData,
-    ) -> Result<f32, Error<E>> {
-        let var1 = uncompensated as f32 / 16384.0 - calibration.dig_t1 as f32 / 1024.0;
-        let var1 = var1 * calibration.dig_t2 as f32;
-        let var2 = uncompensated as f32 / 131072.0 - calibration.dig_t1 as f32 / 8192.0;
-        let var2 = var2 * var2 * calibration.dig_t3 as f32;
-
-        calibration.t_fine = (var1 + var2) as i32;
+/// Measurement data
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct Measurements<E> {
+    /// temperature in degrees celsius
+    pub temperature: f32,
+    /// pressure in pascals
+    pub pressure: f32,
+    /// percent relative humidity, `None` if the chip has no humidity sensor (BMP280) or the
+    /// humidity measurement is skipped
+    pub humidity: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _e: PhantomData<E>,
+}
 
-        let temperature = (var1 + var2) / 5120.0;
-        let temperature = if temperature < BME280_TEMP_MIN {
-            BME280_TEMP_MIN
-        } else if temperature > BME280_TEMP_MAX {
-            BME280_TEMP_MAX
-        } else {
-            temperature
-        };
-        Ok(temperature)
+impl<E> fmt::Display for Measurements<E> {
+    /// Formats the measurements with their units, e.g. `23.4 °C, 1003.2 hPa, 45.1 %RH`, the
+    /// humidity being left out when unavailable
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        core::write!(
+            f,
+            "{:.1} °C, {:.1} hPa",
+            self.temperature,
+            self.pressure_hpa()
+        )?;
+        if let Some(humidity) = self.humidity {
+            core::write!(f, ", {:.1} %RH", humidity)?;
+        }
+        Ok(())
     }
+}
 
-    fn compensate_pressure(
-        uncompensated: u32,
-        calibration: &mut CalibrationData,
-    ) -> Result<f32, Error<E>> {
-        let var1 = calibration.t_fine as f32 / 2.0 - 64000.0;
-        let var2 = var1 * var1 * calibration.dig_p6 as f32 / 32768.0;
-        let var2 = var2 + var1 * calibration.dig_p5 as f32 * 2.0;
-        let var2 = var2 / 4.0 + calibration.dig_p4 as f32 * 65536.0;
-        let var3 = calibration.dig_p3 as f32 * var1 * var1 / 524288.0;
-        let var1 = (var3 + calibration.dig_p2 as f32 * var1) / 524288.0;
-        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f32;
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDisplay for Measurements<E> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        write_tenths(f, self.temperature)?;
+        f.write_str(" °C, ")?;
+        write_tenths(f, self.pressure_hpa())?;
+        f.write_str(" hPa")?;
+        if let Some(humidity) = self.humidity {
+            f.write_str(", ")?;
+            write_tenths(f, humidity)?;
+            f.write_str(" %RH")?;
+        }
+        Ok(())
+    }
+}
 
-        let pressure = if var1 > 0.0 {
-            let pressure = 1048576.0 - uncompensated as f32;
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDebug for Measurements<E> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.write_str("Measurements { temperature: ")?;
+        write_tenths(f, self.temperature)?;
+        f.write_str(", pressure: ")?;
+        write_tenths(f, self.pressure)?;
+        f.write_str(", humidity: ")?;
+        match self.humidity {
+            Some(humidity) => {
+                f.write_str("Some(")?;
+                write_tenths(f, humidity)?;
+                f.write_str(")")?;
+            }
+            None => f.write_str("None")?,
+        }
+        f.write_str(" }")
+    }
+}
+
+/// Writes the `value` with one decimal, ufmt having no support for floating point numbers
+#[cfg(feature = "ufmt")]
+fn write_tenths<W>(f: &mut ufmt::Formatter<'_, W>, value: f32) -> Result<(), W::Error>
+where
+    W: ufmt::uWrite + ?Sized,
+{
+    if value.is_nan() {
+        return f.write_str("NaN");
+    }
+    let tenths = libm::roundf(value * 10.0) as i64;
+    if tenths < 0 {
+        f.write_str("-")?;
+    }
+    let tenths = tenths.unsigned_abs();
+    ufmt::uwrite!(f, "{}.{}", tenths / 10, tenths % 10)
+}
+
+#[cfg(feature = "with_defmt")]
+impl<E> Format for Measurements<E> {
+    fn format(&self, fmt: Formatter) {
+        write!(
+            fmt,
+            "Measurements {{ temperature: {}, pressure: {}, humidity: {} }}",
+            self.temperature, self.pressure, self.humidity
+        )
+    }
+}
+
+/// Length of the encoding of [`Measurements::pack`]
+pub const PACKED_MEASUREMENTS_LEN: usize = 6;
+
+/// Pressure encoded as zero by [`Measurements::pack`], in pascals
+const PACKED_PRESSURE_OFFSET: f32 = 30000.0;
+
+/// Mean, minimum, and maximum over a series of measurements
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct MeasurementStatistics<E> {
+    /// average of each channel
+    pub mean: Measurements<E>,
+    /// smallest value of each channel
+    pub min: Measurements<E>,
+    /// largest value of each channel
+    pub max: Measurements<E>,
+}
+
+impl<E> Measurements<E> {
+    /// Whether the results lie within the operating range of the sensor: -40..85 °C,
+    /// 300..1100 hPa, and 0..100 %RH. As the compensation clamps the results to these ranges,
+    /// a temperature or pressure at one of their limits counts as out of range. The humidity
+    /// legitimately saturates at 0 and 100 %RH, e.g. in dry gas or condensing air, so these
+    /// limits are in range. Skipped measurements are not checked.
+    pub fn in_range(&self) -> bool {
+        let inside = |value: f32, min: f32, max: f32| !(value <= min || value >= max);
+        inside(self.temperature, BME280_TEMP_MIN, BME280_TEMP_MAX)
+            && inside(self.pressure, BME280_PRESSURE_MIN, BME280_PRESSURE_MAX)
+            && match self.humidity {
+                Some(humidity) => (BME280_HUMIDITY_MIN..=BME280_HUMIDITY_MAX).contains(&humidity),
+                None => true,
+            }
+    }
+
+    /// Temperature tagged with its unit
+    pub fn celsius(&self) -> units::Celsius {
+        units::Celsius(self.temperature)
+    }
+
+    /// Pressure tagged with its unit
+    pub fn pascals(&self) -> units::Pascals {
+        units::Pascals(self.pressure)
+    }
+
+    /// Relative humidity tagged with its unit, `None` without a humidity measurement
+    pub fn relative_humidity(&self) -> Option<units::RelativeHumidity> {
+        self.humidity.map(units::RelativeHumidity)
+    }
+
+    /// Temperature in degrees fahrenheit
+    pub fn temperature_f(&self) -> f32 {
+        self.temperature * 1.8 + 32.0
+    }
+
+    /// Temperature in kelvin
+    pub fn temperature_k(&self) -> f32 {
+        self.temperature + derived::ZERO_CELSIUS
+    }
+
+    /// Pressure in hectopascals, i.e. millibars
+    pub fn pressure_hpa(&self) -> f32 {
+        self.pressure / 100.0
+    }
+
+    /// Pressure in kilopascals
+    pub fn pressure_kpa(&self) -> f32 {
+        self.pressure / 1000.0
+    }
+
+    /// Pressure in inches of mercury
+    pub fn pressure_inhg(&self) -> f32 {
+        self.pressure / 3386.389
+    }
+
+    /// Pressure in millimeters of mercury
+    pub fn pressure_mmhg(&self) -> f32 {
+        self.pressure / 133.322_39
+    }
+
+    /// Pressure in pounds per square inch
+    pub fn pressure_psi(&self) -> f32 {
+        self.pressure / 6894.757
+    }
+
+    /// Encodes the measurements into [`PACKED_MEASUREMENTS_LEN`] bytes for radio payloads,
+    /// decoded by [`Measurements::unpack`]. Each channel is a little-endian 16-bit word:
+    ///
+    /// | bytes | channel | encoding | unavailable |
+    /// |-------|---------|----------|-------------|
+    /// | 0..2 | temperature | `i16`, hundredths of a degree celsius | `i16::MIN` |
+    /// | 2..4 | pressure | `u16`, 2 Pa steps above 30000 Pa | `u16::MAX` |
+    /// | 4..6 | humidity | `u16`, hundredths of a percent | `u16::MAX` |
+    ///
+    /// Values outside the sensor's operating range are clamped to it.
+    pub fn pack(&self) -> [u8; PACKED_MEASUREMENTS_LEN] {
+        let temperature = if self.temperature.is_nan() {
+            i16::MIN
+        } else {
+            libm::roundf(self.temperature * 100.0).clamp(-4000.0, 8500.0) as i16
+        };
+        let pressure = if self.pressure.is_nan() {
+            u16::MAX
+        } else {
+            libm::roundf((self.pressure - PACKED_PRESSURE_OFFSET) / 2.0).clamp(0.0, 40000.0) as u16
+        };
+        let humidity = match self.humidity {
+            Some(humidity) => libm::roundf(humidity * 100.0).clamp(0.0, 10000.0) as u16,
+            None => u16::MAX,
+        };
+
+        let mut bytes = [0; PACKED_MEASUREMENTS_LEN];
+        bytes[0..2].copy_from_slice(&temperature.to_le_bytes());
+        bytes[2..4].copy_from_slice(&pressure.to_le_bytes());
+        bytes[4..6].copy_from_slice(&humidity.to_le_bytes());
+        bytes
+    }
+
+    /// Decodes measurements encoded by [`Measurements::pack`]. Unavailable temperatures and
+    /// pressures are NaN.
+    pub fn unpack(bytes: &[u8; PACKED_MEASUREMENTS_LEN]) -> Self {
+        let temperature = i16::from_le_bytes([bytes[0], bytes[1]]);
+        let pressure = u16::from_le_bytes([bytes[2], bytes[3]]);
+        let humidity = u16::from_le_bytes([bytes[4], bytes[5]]);
+        Measurements::from_channels([
+            match temperature {
+                i16::MIN => f32::NAN,
+                temperature => temperature as f32 / 100.0,
+            },
+            match pressure {
+                u16::MAX => f32::NAN,
+                pressure => pressure as f32 * 2.0 + PACKED_PRESSURE_OFFSET,
+            },
+            match humidity {
+                u16::MAX => f32::NAN,
+                humidity => humidity as f32 / 100.0,
+            },
+        ])
+    }
+
+    /// Serializes the measurements as a JSON object with the keys `temperature` in degrees
+    /// celsius, `pressure` in pascals, and `humidity` in percent, unavailable values being
+    /// `null`, e.g. `{"temperature":23.4,"pressure":100320,"humidity":45.1}`
+    #[cfg(feature = "with_std")]
+    pub fn to_json(&self) -> String {
+        fn value(value: Option<f32>) -> String {
+            match value {
+                Some(value) if value.is_finite() => format!("{}", value),
+                _ => "null".into(),
+            }
+        }
+        format!(
+            r#"{{"temperature":{},"pressure":{},"humidity":{}}}"#,
+            value(Some(self.temperature)),
+            value(Some(self.pressure)),
+            value(self.humidity)
+        )
+    }
+
+    /// Altitude in meters, given the current pressure at sea level in pascals, e.g.
+    /// [`derived::SEA_LEVEL_PRESSURE`]
+    pub fn altitude(&self, sea_level_pressure: f32) -> f32 {
+        derived::altitude(self.pressure, sea_level_pressure)
+    }
+
+    /// Pressure reduced to sea level in pascals, given the altitude of the sensor in meters
+    pub fn sea_level_pressure(&self, altitude: f32) -> f32 {
+        derived::sea_level_pressure(self.pressure, altitude)
+    }
+
+    /// Dew point in degrees celsius, `None` without a humidity measurement or at 0 %RH, where
+    /// the air holds no water vapour to condense
+    pub fn dew_point(&self) -> Option<f32> {
+        self.humidity
+            .filter(|&humidity| humidity > 0.0)
+            .map(|humidity| derived::dew_point(self.temperature, humidity))
+    }
+
+    /// Whether the dew point comes within `margin` degrees of the `surface_temperature` in
+    /// degrees celsius, `None` without a humidity measurement
+    pub fn condensation_risk(&self, surface_temperature: f32, margin: f32) -> Option<bool> {
+        self.humidity.map(|humidity| {
+            derived::condensation_risk(self.temperature, humidity, surface_temperature, margin)
+        })
+    }
+
+    /// Frost point in degrees celsius, `None` without a humidity measurement or at 0 %RH
+    pub fn frost_point(&self) -> Option<f32> {
+        self.humidity
+            .filter(|&humidity| humidity > 0.0)
+            .map(|humidity| derived::frost_point(self.temperature, humidity))
+    }
+
+    /// Partial pressure of the water vapour in pascals, `None` without a humidity measurement
+    pub fn vapor_pressure(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::vapor_pressure(self.temperature, humidity))
+    }
+
+    /// Absolute humidity in grams of water vapour per cubic meter, `None` without a humidity
+    /// measurement
+    pub fn absolute_humidity(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::absolute_humidity(self.temperature, humidity))
+    }
+
+    /// Heat index in degrees celsius, `None` without a humidity measurement
+    pub fn heat_index(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::heat_index(self.temperature, humidity))
+    }
+
+    /// Wet-bulb temperature in degrees celsius, `None` without a humidity measurement
+    pub fn wet_bulb_temperature(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::wet_bulb_temperature(self.temperature, humidity))
+    }
+
+    /// Humidex in degrees celsius, `None` without a humidity measurement
+    pub fn humidex(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::humidex(self.temperature, humidity))
+    }
+
+    /// Boiling point of water in degrees celsius at the measured pressure
+    pub fn boiling_point(&self) -> f32 {
+        derived::boiling_point(self.pressure)
+    }
+
+    /// Comfort of the conditions relative to the `zone`, `None` without a humidity measurement
+    pub fn comfort(&self, zone: &comfort::ComfortZone) -> Option<comfort::Comfort> {
+        self.humidity
+            .map(|humidity| zone.classify(self.temperature, humidity))
+    }
+
+    /// Density of the air in kilograms per cubic meter, that of dry air without a humidity
+    /// measurement
+    pub fn air_density(&self) -> f32 {
+        derived::air_density(
+            self.temperature,
+            self.pressure,
+            self.humidity.unwrap_or(0.0),
+        )
+    }
+
+    /// Deviation in degrees of the temperature from the standard atmosphere at the pressure
+    /// altitude
+    pub fn isa_deviation(&self) -> f32 {
+        isa::temperature_deviation(
+            self.temperature,
+            derived::altitude(self.pressure, derived::SEA_LEVEL_PRESSURE),
+        )
+    }
+
+    /// Density altitude in meters, from the pressure altitude and the temperature
+    pub fn density_altitude(&self) -> f32 {
+        derived::density_altitude(
+            derived::altitude(self.pressure, derived::SEA_LEVEL_PRESSURE),
+            self.temperature,
+        )
+    }
+
+    fn from_channels([temperature, pressure, humidity]: [f32; 3]) -> Self {
+        Measurements {
+            temperature,
+            pressure,
+            humidity: if humidity.is_nan() {
+                None
+            } else {
+                Some(humidity)
+            },
+            _e: PhantomData,
+        }
+    }
+
+    fn parse(
+        data: [u8; BME280_P_T_H_DATA_LEN],
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        Measurements::compensate(RawData::parse(data), calibration)
+    }
+
+    fn compensate(data: RawData, calibration: &mut CalibrationData) -> Result<Self, Error<E>> {
+        let temperature = Measurements::<E>::compensate_temperature(data.temperature, calibration);
+        let (pressure, humidity) = if temperature.is_nan() {
+            (f32::NAN, None)
+        } else {
+            (
+                Measurements::compensate_pressure(data.pressure, calibration)?,
+                Measurements::<E>::compensate_humidity(data.humidity, calibration),
+            )
+        };
+
+        Ok(Measurements {
+            temperature,
+            pressure,
+            humidity,
+            _e: PhantomData,
+        })
+    }
+
+    fn compensate_temperature(uncompensated: u32, calibration: &mut CalibrationData) -> f32 {
+        if uncompensated == BME280_TEMPERATURE_SKIPPED {
+            return f32::NAN;
+        }
+        let var1 = uncompensated as f32 / 16384.0 - calibration.dig_t1 as f32 / 1024.0;
+        let var1 = var1 * calibration.dig_t2 as f32;
+        let var2 = uncompensated as f32 / 131072.0 - calibration.dig_t1 as f32 / 8192.0;
+        let var2 = var2 * var2 * calibration.dig_t3 as f32;
+
+        calibration.t_fine = (var1 + var2) as i32;
+
+        let temperature = (var1 + var2) / 5120.0;
+        if temperature < BME280_TEMP_MIN {
+            BME280_TEMP_MIN
+        } else if temperature > BME280_TEMP_MAX {
+            BME280_TEMP_MAX
+        } else {
+            temperature
+        }
+    }
+
+    #[cfg(feature = "pressure")]
+    fn compensate_pressure(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<f32, Error<E>> {
+        if uncompensated == BME280_PRESSURE_SKIPPED {
+            return Ok(f32::NAN);
+        }
+        let var1 = calibration.t_fine as f32 / 2.0 - 64000.0;
+        let var2 = var1 * var1 * calibration.dig_p6 as f32 / 32768.0;
+        let var2 = var2 + var1 * calibration.dig_p5 as f32 * 2.0;
+        let var2 = var2 / 4.0 + calibration.dig_p4 as f32 * 65536.0;
+        let var3 = calibration.dig_p3 as f32 * var1 * var1 / 524288.0;
+        let var1 = (var3 + calibration.dig_p2 as f32 * var1) / 524288.0;
+        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f32;
+
+        let pressure = if var1 > 0.0 {
+            let pressure = 1048576.0 - uncompensated as f32;
             let pressure = (pressure - (var2 / 4096.0)) * 6250.0 / var1;
             let var1 = calibration.dig_p9 as f32 * pressure * pressure / 2147483648.0;
             let var2 = pressure * calibration.dig_p8 as f32 / 32768.0;
@@ -443,10 +1625,19 @@ impl<E> Measurements<E> {
         Ok(pressure)
     }
 
-    fn compensate_humidity(
-        uncompensated: u32,
-        calibration: &mut CalibrationData,
+    #[cfg(not(feature = "pressure"))]
+    fn compensate_pressure(
+        _uncompensated: u32,
+        _calibration: &mut CalibrationData,
     ) -> Result<f32, Error<E>> {
+        Ok(f32::NAN)
+    }
+
+    #[cfg(feature = "humidity")]
+    fn compensate_humidity(uncompensated: u32, calibration: &mut CalibrationData) -> Option<f32> {
+        if uncompensated == BME280_HUMIDITY_SKIPPED {
+            return None;
+        }
         let var1 = calibration.t_fine as f32 - 76800.0;
         let var2 = calibration.dig_h4 as f32 * 64.0 + (calibration.dig_h5 as f32 / 16384.0) * var1;
         let var3 = uncompensated as f32 - var2;
@@ -463,31 +1654,698 @@ impl<E> Measurements<E> {
         } else {
             humidity
         };
-        Ok(humidity)
+        Some(humidity)
+    }
+
+    #[cfg(not(feature = "humidity"))]
+    fn compensate_humidity(_uncompensated: u32, _calibration: &mut CalibrationData) -> Option<f32> {
+        None
+    }
+}
+
+/// Compensates the raw ADC values of a measurement, e.g. one logged with `measure_raw`.
+pub fn compensate<E>(
+    data: RawData,
+    calibration: &CalibrationData,
+) -> Result<Measurements<E>, Error<E>> {
+    let mut calibration = *calibration;
+    Measurements::compensate(data, &mut calibration)
+}
+
+/// Compensates a raw temperature ADC value, in degrees celsius.
+/// Returns NaN if the temperature measurement is skipped.
+pub fn compensate_temperature(uncompensated: u32, calibration: &CalibrationData) -> f32 {
+    let mut calibration = *calibration;
+    Measurements::<()>::compensate_temperature(uncompensated, &mut calibration)
+}
+
+/// Computes the intermediate `t_fine` value of the floating point compensation from a raw
+/// temperature ADC value, or `None` if the temperature measurement is skipped
+pub fn compensate_t_fine(uncompensated: u32, calibration: &CalibrationData) -> Option<i32> {
+    let mut calibration = *calibration;
+    let temperature = Measurements::<()>::compensate_temperature(uncompensated, &mut calibration);
+    if temperature.is_nan() {
+        None
+    } else {
+        Some(calibration.t_fine)
+    }
+}
+
+/// Compensates a raw pressure ADC value, in pascals. The pressure compensation depends on the
+/// temperature, so the raw temperature of the same measurement is needed as well.
+/// Returns NaN if the pressure or temperature measurement is skipped.
+pub fn compensate_pressure<E>(
+    uncompensated: u32,
+    uncompensated_temperature: u32,
+    calibration: &CalibrationData,
+) -> Result<f32, Error<E>> {
+    let mut calibration = *calibration;
+    let temperature =
+        Measurements::<E>::compensate_temperature(uncompensated_temperature, &mut calibration);
+    if temperature.is_nan() {
+        return Ok(f32::NAN);
+    }
+    Measurements::<E>::compensate_pressure(uncompensated, &mut calibration)
+}
+
+/// Compensates a raw humidity ADC value, in percent relative humidity. The humidity
+/// compensation depends on the temperature, so the raw temperature of the same measurement is
+/// needed as well.
+/// Returns `None` if the humidity or temperature measurement is skipped.
+pub fn compensate_humidity(
+    uncompensated: u32,
+    uncompensated_temperature: u32,
+    calibration: &CalibrationData,
+) -> Option<f32> {
+    let mut calibration = *calibration;
+    let temperature =
+        Measurements::<()>::compensate_temperature(uncompensated_temperature, &mut calibration);
+    if temperature.is_nan() {
+        return None;
+    }
+    Measurements::<()>::compensate_humidity(uncompensated, &mut calibration)
+}
+
+/// Measurement data from the 32-bit integer compensation, which needs no floating point
+#[cfg(feature = "integer")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IntegerMeasurements {
+    /// temperature in hundredths of a degree celsius, `None` if the temperature measurement is
+    /// skipped
+    pub temperature: Option<i32>,
+    /// pressure in pascals, `None` if the pressure or temperature measurement is skipped
+    pub pressure: Option<u32>,
+    /// humidity in thousandths of a percent relative humidity, `None` if the chip has no
+    /// humidity sensor (BMP280) or the humidity or temperature measurement is skipped
+    pub humidity: Option<u32>,
+}
+
+#[cfg(feature = "integer")]
+impl IntegerMeasurements {
+    fn parse<E>(
+        data: [u8; BME280_P_T_H_DATA_LEN],
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        let data = RawData::parse(data);
+
+        let temperature =
+            IntegerMeasurements::compensate_temperature(data.temperature, calibration);
+        let (pressure, humidity) = match temperature {
+            Some(_) => (
+                IntegerMeasurements::compensate_pressure(data.pressure, calibration)?,
+                IntegerMeasurements::compensate_humidity(data.humidity, calibration),
+            ),
+            None => (None, None),
+        };
+
+        Ok(IntegerMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        })
+    }
+
+    fn compensate_temperature(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Option<i32> {
+        if uncompensated == BME280_TEMPERATURE_SKIPPED {
+            return None;
+        }
+        let adc = uncompensated as i32;
+        let dig_t1 = calibration.dig_t1 as i32;
+        let var1 = (((adc >> 3) - (dig_t1 << 1)) * calibration.dig_t2 as i32) >> 11;
+        let var2 = (((((adc >> 4) - dig_t1) * ((adc >> 4) - dig_t1)) >> 12)
+            * calibration.dig_t3 as i32)
+            >> 14;
+
+        calibration.t_fine = var1 + var2;
+
+        let temperature = (calibration.t_fine * 5 + 128) >> 8;
+        Some(temperature.clamp(BME280_TEMP_MIN_INT, BME280_TEMP_MAX_INT))
+    }
+
+    #[cfg(feature = "pressure")]
+    fn compensate_pressure<E>(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<Option<u32>, Error<E>> {
+        if uncompensated == BME280_PRESSURE_SKIPPED {
+            return Ok(None);
+        }
+        let var1 = calibration.t_fine / 2 - 64000;
+        let var2 = (((var1 / 4) * (var1 / 4)) / 2048) * calibration.dig_p6 as i32;
+        let var2 = var2 + ((var1 * calibration.dig_p5 as i32) * 2);
+        let var2 = (var2 / 4) + (calibration.dig_p4 as i32 * 65536);
+        let var3 = (calibration.dig_p3 as i32 * (((var1 / 4) * (var1 / 4)) / 8192)) / 8;
+        let var4 = (calibration.dig_p2 as i32 * var1) / 2;
+        let var1 = (var3 + var4) / 262144;
+        let var1 = ((32768 + var1) * calibration.dig_p1 as i32) / 32768;
+        if var1 <= 0 {
+            return Err(Error::InvalidData);
+        }
+
+        // The unsigned arithmetic of the reference implementation wraps.
+        let pressure = 1048576u32
+            .wrapping_sub(uncompensated)
+            .wrapping_sub((var2 / 4096) as u32)
+            .wrapping_mul(3125);
+        let pressure = if pressure < 0x80000000 {
+            (pressure << 1) / var1 as u32
+        } else {
+            (pressure / var1 as u32) * 2
+        };
+        let var1 =
+            (calibration.dig_p9 as i32 * (((pressure / 8) * (pressure / 8)) / 8192) as i32) / 4096;
+        let var2 = ((pressure / 4) as i32 * calibration.dig_p8 as i32) / 8192;
+        let pressure = (pressure as i32 + (var1 + var2 + calibration.dig_p7 as i32) / 16) as u32;
+        Ok(Some(
+            pressure.clamp(BME280_PRESSURE_MIN_INT, BME280_PRESSURE_MAX_INT),
+        ))
+    }
+
+    #[cfg(not(feature = "pressure"))]
+    fn compensate_pressure<E>(
+        _uncompensated: u32,
+        _calibration: &mut CalibrationData,
+    ) -> Result<Option<u32>, Error<E>> {
+        Ok(None)
+    }
+
+    /// 64-bit compensation of the pressure, in Q24.8 format, i.e. 1/256 pascals
+    #[cfg(feature = "pressure")]
+    fn compensate_pressure_64<E>(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<Option<u32>, Error<E>> {
+        if uncompensated == BME280_PRESSURE_SKIPPED {
+            return Ok(None);
+        }
+        let var1 = calibration.t_fine as i64 - 128000;
+        let var2 = var1 * var1 * calibration.dig_p6 as i64;
+        let var2 = var2 + ((var1 * calibration.dig_p5 as i64) << 17);
+        let var2 = var2 + ((calibration.dig_p4 as i64) << 35);
+        let var1 = ((var1 * var1 * calibration.dig_p3 as i64) >> 8)
+            + ((var1 * calibration.dig_p2 as i64) << 12);
+        let var1 = (((1i64 << 47) + var1) * calibration.dig_p1 as i64) >> 33;
+        if var1 == 0 {
+            return Err(Error::InvalidData);
+        }
+
+        let pressure = 1048576 - uncompensated as i64;
+        let pressure = (((pressure << 31) - var2) * 3125) / var1;
+        let var1 = (calibration.dig_p9 as i64 * (pressure >> 13) * (pressure >> 13)) >> 25;
+        let var2 = (calibration.dig_p8 as i64 * pressure) >> 19;
+        let pressure = ((pressure + var1 + var2) >> 8) + ((calibration.dig_p7 as i64) << 4);
+        let pressure = pressure.clamp(
+            BME280_PRESSURE_MIN_INT as i64 * 256,
+            BME280_PRESSURE_MAX_INT as i64 * 256,
+        );
+        Ok(Some(pressure as u32))
+    }
+
+    #[cfg(not(feature = "pressure"))]
+    fn compensate_pressure_64<E>(
+        _uncompensated: u32,
+        _calibration: &mut CalibrationData,
+    ) -> Result<Option<u32>, Error<E>> {
+        Ok(None)
+    }
+
+    fn compensate_humidity(uncompensated: u32, calibration: &mut CalibrationData) -> Option<u32> {
+        IntegerMeasurements::compensate_humidity_q22_10(uncompensated, calibration)
+            .map(|humidity| humidity * 1000 / 1024)
+    }
+
+    /// 32-bit compensation of the humidity, in Q22.10 format, i.e. 1/1024 percent
+    #[cfg(feature = "humidity")]
+    fn compensate_humidity_q22_10(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Option<u32> {
+        if uncompensated == BME280_HUMIDITY_SKIPPED {
+            return None;
+        }
+        let var1 = calibration.t_fine - 76800;
+        let var2 = ((uncompensated as i32) << 14)
+            - ((calibration.dig_h4 as i32) << 20)
+            - (calibration.dig_h5 as i32 * var1);
+        let var2 = (var2 + 16384) >> 15;
+        let var3 = (var1 * calibration.dig_h6 as i32) >> 10;
+        let var4 = ((var1 * calibration.dig_h3 as i32) >> 11) + 32768;
+        let var5 = (((var3 * var4) >> 10) + 2097152) * calibration.dig_h2 as i32 + 8192;
+        let var1 = var2 * (var5 >> 14);
+        let var1 = var1 - (((((var1 >> 15) * (var1 >> 15)) >> 7) * calibration.dig_h1 as i32) >> 4);
+
+        // limited to 0..100%
+        Some((var1.clamp(0, 419430400) >> 12) as u32)
+    }
+
+    #[cfg(not(feature = "humidity"))]
+    fn compensate_humidity_q22_10(
+        _uncompensated: u32,
+        _calibration: &mut CalibrationData,
+    ) -> Option<u32> {
+        None
+    }
+}
+
+/// Measurement data as typed quantities of the `uom` crate
+#[cfg(feature = "uom")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct QuantityMeasurements {
+    /// temperature
+    pub temperature: ThermodynamicTemperature,
+    /// pressure
+    pub pressure: Pressure,
+    /// relative humidity, `None` if the chip has no humidity sensor (BMP280) or the humidity
+    /// measurement is skipped
+    pub humidity: Option<Ratio>,
+}
+
+#[cfg(feature = "uom")]
+impl<E> From<Measurements<E>> for QuantityMeasurements {
+    fn from(measurements: Measurements<E>) -> Self {
+        QuantityMeasurements {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(measurements.temperature),
+            pressure: Pressure::new::<pascal>(measurements.pressure),
+            humidity: measurements.humidity.map(Ratio::new::<percent>),
+        }
     }
 }
 
-trait Interface {
+/// Measurement data from the double-precision floating point compensation
+#[cfg(feature = "f64")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DoubleMeasurements {
+    /// temperature in degrees celsius
+    pub temperature: f64,
+    /// pressure in pascals
+    pub pressure: f64,
+    /// percent relative humidity, `None` if the chip has no humidity sensor (BMP280) or the
+    /// humidity measurement is skipped
+    pub humidity: Option<f64>,
+}
+
+#[cfg(feature = "f64")]
+impl DoubleMeasurements {
+    fn parse<E>(
+        data: [u8; BME280_P_T_H_DATA_LEN],
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        let data = RawData::parse(data);
+
+        let temperature = DoubleMeasurements::compensate_temperature(data.temperature, calibration);
+        let (pressure, humidity) = if temperature.is_nan() {
+            (f64::NAN, None)
+        } else {
+            (
+                DoubleMeasurements::compensate_pressure(data.pressure, calibration)?,
+                DoubleMeasurements::compensate_humidity(data.humidity, calibration),
+            )
+        };
+
+        Ok(DoubleMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        })
+    }
+
+    fn compensate_temperature(uncompensated: u32, calibration: &mut CalibrationData) -> f64 {
+        if uncompensated == BME280_TEMPERATURE_SKIPPED {
+            return f64::NAN;
+        }
+        let var1 = uncompensated as f64 / 16384.0 - calibration.dig_t1 as f64 / 1024.0;
+        let var1 = var1 * calibration.dig_t2 as f64;
+        let var2 = uncompensated as f64 / 131072.0 - calibration.dig_t1 as f64 / 8192.0;
+        let var2 = var2 * var2 * calibration.dig_t3 as f64;
+
+        calibration.t_fine = (var1 + var2) as i32;
+
+        let temperature = (var1 + var2) / 5120.0;
+        temperature.clamp(BME280_TEMP_MIN as f64, BME280_TEMP_MAX as f64)
+    }
+
+    #[cfg(feature = "pressure")]
+    fn compensate_pressure<E>(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<f64, Error<E>> {
+        if uncompensated == BME280_PRESSURE_SKIPPED {
+            return Ok(f64::NAN);
+        }
+        let var1 = calibration.t_fine as f64 / 2.0 - 64000.0;
+        let var2 = var1 * var1 * calibration.dig_p6 as f64 / 32768.0;
+        let var2 = var2 + var1 * calibration.dig_p5 as f64 * 2.0;
+        let var2 = var2 / 4.0 + calibration.dig_p4 as f64 * 65536.0;
+        let var3 = calibration.dig_p3 as f64 * var1 * var1 / 524288.0;
+        let var1 = (var3 + calibration.dig_p2 as f64 * var1) / 524288.0;
+        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f64;
+        if var1 <= 0.0 {
+            return Err(Error::InvalidData);
+        }
+
+        let pressure = 1048576.0 - uncompensated as f64;
+        let pressure = (pressure - (var2 / 4096.0)) * 6250.0 / var1;
+        let var1 = calibration.dig_p9 as f64 * pressure * pressure / 2147483648.0;
+        let var2 = pressure * calibration.dig_p8 as f64 / 32768.0;
+        let pressure = pressure + (var1 + var2 + calibration.dig_p7 as f64) / 16.0;
+        Ok(pressure.clamp(BME280_PRESSURE_MIN as f64, BME280_PRESSURE_MAX as f64))
+    }
+
+    #[cfg(not(feature = "pressure"))]
+    fn compensate_pressure<E>(
+        _uncompensated: u32,
+        _calibration: &mut CalibrationData,
+    ) -> Result<f64, Error<E>> {
+        Ok(f64::NAN)
+    }
+
+    #[cfg(feature = "humidity")]
+    fn compensate_humidity(uncompensated: u32, calibration: &mut CalibrationData) -> Option<f64> {
+        if uncompensated == BME280_HUMIDITY_SKIPPED {
+            return None;
+        }
+        let var1 = calibration.t_fine as f64 - 76800.0;
+        let var2 = calibration.dig_h4 as f64 * 64.0 + (calibration.dig_h5 as f64 / 16384.0) * var1;
+        let var3 = uncompensated as f64 - var2;
+        let var4 = calibration.dig_h2 as f64 / 65536.0;
+        let var5 = 1.0 + (calibration.dig_h3 as f64 / 67108864.0) * var1;
+        let var6 = 1.0 + (calibration.dig_h6 as f64 / 67108864.0) * var1 * var5;
+        let var6 = var3 * var4 * (var5 * var6);
+
+        let humidity = var6 * (1.0 - calibration.dig_h1 as f64 * var6 / 524288.0);
+        Some(humidity.clamp(BME280_HUMIDITY_MIN as f64, BME280_HUMIDITY_MAX as f64))
+    }
+
+    #[cfg(not(feature = "humidity"))]
+    fn compensate_humidity(_uncompensated: u32, _calibration: &mut CalibrationData) -> Option<f64> {
+        None
+    }
+}
+
+/// Measurement data from the 32-bit integer compensation in milli-units, e.g. for sending over
+/// a bus without encoding floating point numbers
+#[cfg(feature = "integer")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MeasurementsMilli {
+    /// temperature in thousandths of a degree celsius, `None` if the temperature measurement is
+    /// skipped
+    pub temperature_mc: Option<i32>,
+    /// pressure in pascals, `None` if the pressure or temperature measurement is skipped
+    pub pressure_pa: Option<u32>,
+    /// humidity in thousandths of a percent relative humidity, `None` if the chip has no
+    /// humidity sensor (BMP280) or the humidity or temperature measurement is skipped
+    pub humidity_mpct: Option<u32>,
+}
+
+#[cfg(feature = "integer")]
+impl MeasurementsMilli {
+    fn parse<E>(
+        data: [u8; BME280_P_T_H_DATA_LEN],
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        let data = RawData::parse(data);
+
+        if IntegerMeasurements::compensate_temperature(data.temperature, calibration).is_none() {
+            return Ok(MeasurementsMilli {
+                temperature_mc: None,
+                pressure_pa: None,
+                humidity_mpct: None,
+            });
+        }
+        // t_fine is in 1/5120 degrees, which gives a finer resolution than the hundredths
+        // returned by the temperature compensation
+        let temperature_mc = Some(
+            ((calibration.t_fine * 50 + 128) >> 8)
+                .clamp(BME280_TEMP_MIN_INT * 10, BME280_TEMP_MAX_INT * 10),
+        );
+        let pressure_pa = IntegerMeasurements::compensate_pressure(data.pressure, calibration)?;
+        let humidity_mpct = IntegerMeasurements::compensate_humidity(data.humidity, calibration);
+
+        Ok(MeasurementsMilli {
+            temperature_mc,
+            pressure_pa,
+            humidity_mpct,
+        })
+    }
+}
+
+/// Measurement data from the integer compensation as fixed-point numbers
+#[cfg(feature = "with_fixed")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FixedMeasurements {
+    /// temperature in degrees celsius, `None` if the temperature measurement is skipped
+    pub temperature: Option<I16F16>,
+    /// pressure in pascals, `None` if the pressure or temperature measurement is skipped
+    pub pressure: Option<U24F8>,
+    /// percent relative humidity, `None` if the chip has no humidity sensor (BMP280) or the
+    /// humidity or temperature measurement is skipped
+    pub humidity: Option<U22F10>,
+}
+
+#[cfg(feature = "with_fixed")]
+impl FixedMeasurements {
+    fn parse<E>(
+        data: [u8; BME280_P_T_H_DATA_LEN],
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        let data = RawData::parse(data);
+
+        // hundredths of a degree, rescaled to 16 fractional bits
+        let temperature =
+            IntegerMeasurements::compensate_temperature(data.temperature, calibration)
+                .map(|temperature| I16F16::from_bits((temperature << 16) / 100));
+        let (pressure, humidity) = match temperature {
+            Some(_) => (
+                IntegerMeasurements::compensate_pressure_64(data.pressure, calibration)?
+                    .map(U24F8::from_bits),
+                IntegerMeasurements::compensate_humidity_q22_10(data.humidity, calibration)
+                    .map(U22F10::from_bits),
+            ),
+            None => (None, None),
+        };
+
+        Ok(FixedMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        })
+    }
+}
+
+/// Corrections applied to the compensated measurements
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+struct Offsets {
+    /// trim offset in degrees celsius
+    temperature: f32,
+    /// trim offset in pascals
+    pressure: f32,
+    /// trim offset in percent relative humidity
+    humidity: f32,
+    /// temperature rise of the sensor over the ambient air by self-heating, in degrees celsius
+    self_heating: f32,
+}
+
+impl Offsets {
+    fn temperature(&self, temperature: f32) -> f32 {
+        temperature - self.self_heating + self.temperature
+    }
+
+    fn pressure(&self, pressure: f32) -> f32 {
+        pressure + self.pressure
+    }
+
+    /// Corrects the humidity measured at the (uncorrected) `temperature`
+    fn humidity(&self, humidity: Option<f32>, temperature: f32) -> Option<f32> {
+        humidity.map(|humidity| {
+            // The water vapour pressure is the same at the sensor and in the ambient air, so
+            // the relative humidity scales with the inverse of the saturation vapour pressure.
+            let humidity = if self.self_heating != 0.0 {
+                let ambient = temperature - self.self_heating;
+                humidity * derived::saturation_vapor_pressure(temperature)
+                    / derived::saturation_vapor_pressure(ambient)
+            } else {
+                humidity
+            };
+            (humidity + self.humidity).clamp(BME280_HUMIDITY_MIN, BME280_HUMIDITY_MAX)
+        })
+    }
+
+    fn apply<E>(&self, measurements: Measurements<E>) -> Measurements<E> {
+        Measurements {
+            temperature: self.temperature(measurements.temperature),
+            pressure: self.pressure(measurements.pressure),
+            humidity: self.humidity(measurements.humidity, measurements.temperature),
+            _e: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    fn apply_f64(&self, measurements: DoubleMeasurements) -> DoubleMeasurements {
+        let temperature = measurements.temperature;
+        let self_heating = self.self_heating as f64;
+        let humidity = measurements.humidity.map(|humidity| {
+            let humidity = if self_heating != 0.0 {
+                humidity * derived::saturation_vapor_pressure_f64(temperature)
+                    / derived::saturation_vapor_pressure_f64(temperature - self_heating)
+            } else {
+                humidity
+            };
+            (humidity + self.humidity as f64)
+                .clamp(BME280_HUMIDITY_MIN as f64, BME280_HUMIDITY_MAX as f64)
+        });
+        DoubleMeasurements {
+            temperature: temperature - self_heating + self.temperature as f64,
+            pressure: measurements.pressure + self.pressure as f64,
+            humidity,
+        }
+    }
+
+    /// Applies the offsets to the results of an integer compensation, given in `1/scale[0]`
+    /// degrees celsius, `1/scale[1]` pascals, and `1/scale[2]` percent relative humidity
+    #[cfg(feature = "integer")]
+    fn apply_scaled(&self, channels: IntegerChannels, scale: [f32; 3]) -> IntegerChannels {
+        let (temperature, pressure, humidity) = channels;
+        let scaled = |value: f32, scale: f32| libm::roundf(value * scale) as i64;
+        let humidity = humidity.zip(temperature).map(|(humidity, temperature)| {
+            let humidity = if self.self_heating != 0.0 {
+                let temperature = temperature as f32 / scale[0];
+                let ambient = temperature - self.self_heating;
+                let factor = derived::saturation_vapor_pressure(temperature)
+                    / derived::saturation_vapor_pressure(ambient);
+                scaled(humidity as f32, factor)
+            } else {
+                humidity as i64
+            };
+            (humidity + scaled(self.humidity, scale[2])).clamp(
+                scaled(BME280_HUMIDITY_MIN, scale[2]),
+                scaled(BME280_HUMIDITY_MAX, scale[2]),
+            ) as u32
+        });
+        let temperature = temperature.map(|temperature| {
+            (temperature as i64 + scaled(self.temperature - self.self_heating, scale[0]))
+                .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+        });
+        let pressure = pressure.map(|pressure| {
+            (pressure as i64 + scaled(self.pressure, scale[1])).clamp(0, u32::MAX as i64) as u32
+        });
+        (temperature, pressure, humidity)
+    }
+}
+
+/// Temperature, pressure, and humidity from an integer compensation, in the scaled units of
+/// the respective measurement type
+#[cfg(feature = "integer")]
+type IntegerChannels = (Option<i32>, Option<u32>, Option<u32>);
+
+/// Detection of a hung sensor, which keeps returning the same conversion results
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+struct StuckDetector {
+    /// number of consecutive identical frames reported as stuck, 0 to disable the detection
+    limit: u8,
+    /// raw data of the previous conversion
+    previous: [u8; BME280_P_T_H_DATA_LEN],
+    /// number of consecutive conversions that returned `previous`
+    repeats: u8,
+}
+
+impl StuckDetector {
+    /// Records the raw data of a conversion, returning whether the sensor looks stuck
+    fn update(&mut self, data: &[u8; BME280_P_T_H_DATA_LEN]) -> bool {
+        if self.repeats > 0 && *data == self.previous {
+            self.repeats = self.repeats.saturating_add(1);
+        } else {
+            self.previous = *data;
+            self.repeats = 1;
+        }
+        self.limit > 0 && self.repeats >= self.limit
+    }
+
+    fn reset(&mut self) {
+        self.repeats = 0;
+    }
+}
+
+/// Contents of the status register
+#[derive(Debug, Copy, Clone)]
+struct Status {
+    /// A conversion is running
+    measuring: bool,
+    /// The calibration data is being copied from the NVM
+    im_update: bool,
+}
+
+/// Progress of a measurement started with `start_measurement`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+enum MeasurementState {
+    /// No conversion has been started
+    #[default]
+    Idle,
+    /// A forced conversion has been started and not been read yet
+    Pending,
+}
+
+/// Register access to a BME280.
+///
+/// The I2C and SPI drivers implement this on top of the `embedded-hal` traits. Implement it for
+/// other transports, e.g. a bus bridged over RPC or the network, and drive the sensor with
+/// [`custom::BME280`]. With the `async` feature, `AsyncInterface` is the async counterpart used
+/// by `custom::AsyncBME280`.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async", self = "AsyncInterface"))]
+#[allow(async_fn_in_trait)]
+pub trait Interface {
+    /// Error type of the underlying transport
     type Error;
 
-    fn read_register(&mut self, register: u8) -> Result<u8, Error<Self::Error>>;
+    /// Reads a single register.
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<Self::Error>>;
 
-    fn read_data(
+    /// Burst-reads the pressure, temperature, and humidity data registers starting at `register`.
+    async fn read_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<Self::Error>>;
 
-    fn read_pt_calib_data(
+    /// Burst-reads the pressure and temperature calibration data starting at `register`.
+    async fn read_pt_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<Self::Error>>;
 
-    fn read_h_calib_data(
+    /// Burst-reads the humidity calibration data starting at `register`.
+    async fn read_h_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<Self::Error>>;
 
-    fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<Self::Error>>;
+    /// Writes `payload` to a single register.
+    async fn write_register(&mut self, register: u8, payload: u8)
+        -> Result<(), Error<Self::Error>>;
+}
+
+/// Iterator over the samples of a sensor running in normal mode
+#[derive(Debug)]
+struct Samples<'a, I, D> {
+    common: &'a mut BME280Common<I>,
+    delay: &'a mut D,
+}
+
+impl<'a, I, D> Iterator for Samples<'a, I, D>
+where
+    I: Interface,
+    D: DelayNs,
+{
+    type Item = Result<Measurements<I::Error>, Error<I::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.delay.delay_us(self.common.config.output_period_us());
+        Some(self.common.read_latest())
+    }
 }
 
 /// Common driver code for I2C and SPI interfaces
@@ -497,133 +2355,996 @@ struct BME280Common<I> {
     interface: I,
     /// calibration data
     calibration: Option<CalibrationData>,
+    /// state of the non-blocking measurement
+    measurement_state: MeasurementState,
+    /// configuration last applied to the chip
+    config: Configuration,
+    /// verify the configuration before each measurement and restore it if it was lost
+    check_config: bool,
+    /// reject results outside the operating range of the sensor
+    check_range: bool,
+    /// detection of consecutive identical conversions
+    stuck: StuckDetector,
+    /// set while a register sequence is in progress; still set if it was cancelled or failed
+    in_flight: bool,
+    /// chip variant read during initialization
+    variant: Option<ChipVariant>,
+    /// non-standard chip ID to accept, and the variant the chip is compatible with
+    chip_id_override: Option<(u8, ChipVariant)>,
+    /// trim offsets added to the compensated measurements
+    offsets: Offsets,
+}
+
+/// Common async driver code for I2C and SPI interfaces
+#[cfg(feature = "async")]
+#[derive(Debug, Default)]
+struct AsyncBME280Common<I> {
+    /// Interface to the chip (either I2C or SPI)
+    interface: I,
+    /// calibration data
+    calibration: Option<CalibrationData>,
+    /// configuration last applied to the chip
+    config: Configuration,
+    /// verify the configuration before each measurement and restore it if it was lost
+    check_config: bool,
+    /// reject results outside the operating range of the sensor
+    check_range: bool,
+    /// detection of consecutive identical conversions
+    stuck: StuckDetector,
+    /// set while a register sequence is in progress; still set if it was cancelled or failed
+    in_flight: bool,
+    /// chip variant read during initialization
+    variant: Option<ChipVariant>,
+    /// non-standard chip ID to accept, and the variant the chip is compatible with
+    chip_id_override: Option<(u8, ChipVariant)>,
+    /// trim offsets added to the compensated measurements
+    offsets: Offsets,
 }
 
+impl<I> BME280Common<I> {
+    fn new(interface: I) -> Self {
+        BME280Common {
+            interface,
+            calibration: None,
+            measurement_state: MeasurementState::Idle,
+            config: Configuration::reset_state(),
+            check_config: false,
+            check_range: false,
+            stuck: StuckDetector::default(),
+            in_flight: false,
+            variant: None,
+            chip_id_override: None,
+            offsets: Offsets::default(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I> AsyncBME280Common<I> {
+    fn new(interface: I) -> Self {
+        AsyncBME280Common {
+            interface,
+            calibration: None,
+            config: Configuration::reset_state(),
+            check_config: false,
+            check_range: false,
+            stuck: StuckDetector::default(),
+            in_flight: false,
+            variant: None,
+            chip_id_override: None,
+            offsets: Offsets::default(),
+        }
+    }
+}
+
+// The blocking and the async driver share this implementation. It is written as async code, from
+// which `maybe_async_cfg` derives the blocking version by stripping `async` and `.await`.
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync = "Interface", async = "AsyncInterface"),
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280Common")
+)]
 impl<I> BME280Common<I>
 where
     I: Interface,
 {
+    // The bus accesses tag their errors with the operation, see `Error::BusOperation`.
+
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<I::Error>> {
+        let result = self.interface.read_register(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn read_pt_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_pt_calib_data(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn read_h_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_h_calib_data(register).await;
+        result.map_err(|e| e.during(Operation::Read(register)))
+    }
+
+    async fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I::Error>> {
+        let result = self.interface.write_register(register, payload).await;
+        result.map_err(|e| e.during(Operation::Write(register)))
+    }
+
+    /// Writes a register on behalf of the application. A write to one of the configuration
+    /// registers or to the reset register makes the next sequence rewrite all configuration
+    /// registers with the configuration last applied.
+    async fn write_raw_register(
+        &mut self,
+        register: u8,
+        payload: u8,
+    ) -> Result<(), Error<I::Error>> {
+        if matches!(
+            register,
+            BME280_CTRL_HUM_ADDR | BME280_CTRL_MEAS_ADDR | BME280_CONFIG_ADDR | BME280_RESET_ADDR
+        ) {
+            self.in_flight = true;
+        }
+        if register == BME280_RESET_ADDR {
+            self.stuck.reset();
+        }
+        self.write_register(register, payload).await
+    }
+
     /// Initializes the BME280, applying the given config.
-    fn init<D: DelayUs>(
+    async fn init<D: DelayNs>(
         &mut self,
         delay: &mut D,
         config: Configuration,
     ) -> Result<(), Error<I::Error>> {
-        self.verify_chip_id()?;
-        self.soft_reset(delay)?;
-        self.calibrate()?;
-        self.configure(delay, config)
+        self.verify_chip_id().await?;
+        self.soft_reset(delay).await?;
+        self.calibrate().await?;
+        self.configure(config).await
     }
 
-    fn verify_chip_id(&mut self) -> Result<(), Error<I::Error>> {
-        let chip_id = self.interface.read_register(BME280_CHIP_ID_ADDR)?;
-        if chip_id == BME280_CHIP_ID || chip_id == BMP280_CHIP_ID {
-            Ok(())
-        } else {
-            Err(Error::UnsupportedChip)
+    async fn verify_chip_id(&mut self) -> Result<(), Error<I::Error>> {
+        let chip_id = self.read_register(BME280_CHIP_ID_ADDR).await?;
+        self.variant = Some(ChipVariant::from_chip_id(chip_id));
+        match self.compatible_variant() {
+            Some(ChipVariant::Bme280) | Some(ChipVariant::Bmp280) => Ok(()),
+            _ => Err(Error::UnexpectedChipId {
+                expected: BME280_CHIP_ID,
+                found: chip_id,
+            }),
+        }
+    }
+
+    /// The variant the chip behaves as, taking an accepted non-standard chip ID into account
+    fn compatible_variant(&self) -> Option<ChipVariant> {
+        match (self.variant, self.chip_id_override) {
+            (Some(ChipVariant::Unknown(chip_id)), Some((accepted, compatible)))
+                if chip_id == accepted =>
+            {
+                Some(compatible)
+            }
+            (variant, _) => variant,
         }
     }
 
-    fn soft_reset<D: DelayUs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
-        self.interface
-            .write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)?;
-        delay.delay_ms(2).map_err(|_| Error::Delay)?; // startup time is 2ms
+    /// Whether the chip has a humidity sensor, i.e. it is not a BMP280, and the `humidity`
+    /// feature is enabled
+    fn has_humidity(&self) -> bool {
+        cfg!(feature = "humidity") && self.compatible_variant() != Some(ChipVariant::Bmp280)
+    }
+
+    /// Fails with [`Error::OutOfRange`] if the range check is enabled and the temperature,
+    /// pressure, or humidity in `channels` lies outside the operating range of the sensor,
+    /// channels that are NaN not being checked
+    fn check_range(&self, channels: [f32; 3]) -> Result<(), Error<I::Error>> {
+        if self.check_range && !Measurements::<I::Error>::from_channels(channels).in_range() {
+            return Err(Error::OutOfRange);
+        }
         Ok(())
     }
 
-    fn calibrate(&mut self) -> Result<(), Error<I::Error>> {
-        let pt_calib_data = self
-            .interface
-            .read_pt_calib_data(BME280_P_T_CALIB_DATA_ADDR)?;
-        let h_calib_data = self.interface.read_h_calib_data(BME280_H_CALIB_DATA_ADDR)?;
-        self.calibration = Some(parse_calib_data(&pt_calib_data, &h_calib_data));
+    /// Applies the range check and the offsets to the results of an integer compensation, see
+    /// [`Offsets::apply_scaled`] for the units
+    #[cfg(feature = "integer")]
+    fn process_scaled(
+        &self,
+        channels: IntegerChannels,
+        scale: [f32; 3],
+    ) -> Result<IntegerChannels, Error<I::Error>> {
+        let (temperature, pressure, humidity) = channels;
+        let unscaled = |value: Option<f32>, scale| value.map_or(f32::NAN, |value| value / scale);
+        self.check_range([
+            unscaled(temperature.map(|value| value as f32), scale[0]),
+            unscaled(pressure.map(|value| value as f32), scale[1]),
+            unscaled(humidity.map(|value| value as f32), scale[2]),
+        ])?;
+        Ok(self.offsets.apply_scaled(channels, scale))
+    }
+
+    /// Fails with [`Error::NotInitialized`] until `init` has read the calibration data
+    fn ensure_initialized(&self) -> Result<(), Error<I::Error>> {
+        match self.calibration {
+            Some(_) => Ok(()),
+            None => Err(Error::NotInitialized),
+        }
+    }
+
+    async fn soft_reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)
+            .await?;
+        delay.delay_ms(2).await; // startup time is 2ms
+        self.wait_for_nvm_copy(delay).await?;
+        self.config = Configuration::reset_state();
+        self.in_flight = false;
+        self.stuck.reset();
         Ok(())
     }
 
-    fn configure<D: DelayUs>(
+    /// Polls the status register until the calibration data has been copied from the NVM, which
+    /// is only valid afterwards
+    async fn wait_for_nvm_copy<D: DelayNs>(
         &mut self,
         delay: &mut D,
-        config: Configuration,
     ) -> Result<(), Error<I::Error>> {
-        match self.mode()? {
+        let mut elapsed_ms = 0;
+        while self.status().await?.im_update {
+            if elapsed_ms >= BME280_NVM_COPY_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+        Ok(())
+    }
+
+    /// Soft-resets the chip and reloads the calibration data
+    async fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.soft_reset(delay).await?;
+        self.calibrate().await
+    }
+
+    async fn calibrate(&mut self) -> Result<(), Error<I::Error>> {
+        let pt_calib_data = self.read_pt_calib_data(BME280_P_T_CALIB_DATA_ADDR).await?;
+        let h_calib_data = if self.has_humidity() {
+            self.read_h_calib_data(BME280_H_CALIB_DATA_ADDR).await?
+        } else {
+            [0; BME280_H_CALIB_DATA_LEN]
+        };
+        self.calibration = Some(parse_calib_data(&pt_calib_data, &h_calib_data));
+        Ok(())
+    }
+
+    async fn configure(&mut self, config: Configuration) -> Result<(), Error<I::Error>> {
+        // A previous sequence that was cancelled or failed halfway may have left the registers
+        // partially written, in which case all of them are rewritten.
+        let rewrite = self.in_flight;
+        self.in_flight = true;
+
+        // Writes to the config register are only guaranteed to take effect in sleep mode.
+        match self.mode().await? {
             SensorMode::Sleep => {}
-            _ => self.soft_reset(delay)?,
+            _ => self.write_mode(BME280_SLEEP_MODE).await?,
         };
 
-        self.interface.write_register(
-            BME280_CTRL_HUM_ADDR,
-            config.humidity_oversampling.bits() & BME280_CTRL_HUM_MSK,
-        )?;
+        // The BMP280 has neither a humidity sensor nor the ctrl_hum register.
+        let config = if self.has_humidity() {
+            config
+        } else {
+            config.with_humidity_oversampling(Oversampling::Skip)
+        };
+        // Without the `pressure` feature, the pressure measurement is skipped.
+        #[cfg(not(feature = "pressure"))]
+        let config = config.with_pressure_oversampling(Oversampling::Skip);
+
+        // Only registers whose contents change are written, based on the configuration last
+        // applied to the chip.
+        let current = self.config;
+        let humidity_changed =
+            rewrite || config.humidity_oversampling != current.humidity_oversampling;
+        if humidity_changed && self.has_humidity() {
+            self.write_register(
+                BME280_CTRL_HUM_ADDR,
+                config.humidity_oversampling.bits() & BME280_CTRL_HUM_MSK,
+            )
+            .await?;
+        }
 
         // As per the datasheet, the ctrl_meas register needs to be written after
         // the ctrl_hum register for changes to take effect.
-        let data = self.interface.read_register(BME280_CTRL_MEAS_ADDR)?;
-        let data = set_bits!(
-            data,
+        if humidity_changed
+            || config.pressure_oversampling != current.pressure_oversampling
+            || config.temperature_oversampling != current.temperature_oversampling
+        {
+            let data = self.read_register(BME280_CTRL_MEAS_ADDR).await?;
+            let data = set_bits!(
+                data,
+                BME280_CTRL_PRESS_MSK,
+                BME280_CTRL_PRESS_POS,
+                config.pressure_oversampling.bits()
+            );
+            let data = set_bits!(
+                data,
+                BME280_CTRL_TEMP_MSK,
+                BME280_CTRL_TEMP_POS,
+                config.temperature_oversampling.bits()
+            );
+            self.write_register(BME280_CTRL_MEAS_ADDR, data).await?;
+        }
+
+        if rewrite
+            || config.iir_filter != current.iir_filter
+            || config.standby_time != current.standby_time
+        {
+            let data = self.read_register(BME280_CONFIG_ADDR).await?;
+            let data = set_bits!(
+                data,
+                BME280_FILTER_MSK,
+                BME280_FILTER_POS,
+                config.iir_filter.bits()
+            );
+            let data = set_bits!(
+                data,
+                BME280_STANDBY_MSK,
+                BME280_STANDBY_POS,
+                config.standby_time.bits()
+            );
+            self.write_register(BME280_CONFIG_ADDR, data).await?;
+        }
+
+        self.config = config;
+        self.in_flight = false;
+        Ok(())
+    }
+
+    /// Applies a new configuration after initialization.
+    /// The sensor passes through sleep mode and is put back into normal mode afterwards if it was
+    /// running in normal mode before.
+    async fn apply_config(&mut self, config: Configuration) -> Result<(), Error<I::Error>> {
+        let mode = self.mode().await?;
+        self.configure(config).await?;
+        if mode == SensorMode::Normal {
+            self.write_mode(BME280_NORMAL_MODE).await?;
+        }
+        Ok(())
+    }
+
+    async fn set_oversampling(
+        &mut self,
+        temperature: Oversampling,
+        pressure: Oversampling,
+        humidity: Oversampling,
+    ) -> Result<(), Error<I::Error>> {
+        let config = self
+            .config
+            .with_temperature_oversampling(temperature)
+            .with_pressure_oversampling(pressure)
+            .with_humidity_oversampling(humidity);
+        self.apply_config(config).await
+    }
+
+    async fn set_iir_filter(&mut self, filter: IIRFilter) -> Result<(), Error<I::Error>> {
+        let config = self.config.with_iir_filter(filter);
+        self.apply_config(config).await
+    }
+
+    /// Checks whether the chip still holds the configuration last applied to it.
+    /// A brown-out or an unexpected reset reverts the registers to their defaults.
+    async fn verify_config(&mut self) -> Result<bool, Error<I::Error>> {
+        let config = self.config;
+        let ctrl_hum = if self.has_humidity() {
+            self.read_register(BME280_CTRL_HUM_ADDR).await? & BME280_CTRL_HUM_MSK
+        } else {
+            config.humidity_oversampling.bits()
+        };
+        let ctrl_meas = self.read_register(BME280_CTRL_MEAS_ADDR).await?;
+        let config_reg = self.read_register(BME280_CONFIG_ADDR).await?;
+
+        let expected_ctrl_meas = set_bits!(
+            0,
             BME280_CTRL_PRESS_MSK,
             BME280_CTRL_PRESS_POS,
             config.pressure_oversampling.bits()
         );
-        let data = set_bits!(
-            data,
+        let expected_ctrl_meas = set_bits!(
+            expected_ctrl_meas,
             BME280_CTRL_TEMP_MSK,
             BME280_CTRL_TEMP_POS,
             config.temperature_oversampling.bits()
         );
-        self.interface.write_register(BME280_CTRL_MEAS_ADDR, data)?;
-
-        let data = self.interface.read_register(BME280_CONFIG_ADDR)?;
-        let data = set_bits!(
-            data,
+        let expected_config = set_bits!(
+            0,
             BME280_FILTER_MSK,
             BME280_FILTER_POS,
             config.iir_filter.bits()
         );
-        self.interface.write_register(BME280_CONFIG_ADDR, data)
+        let expected_config = set_bits!(
+            expected_config,
+            BME280_STANDBY_MSK,
+            BME280_STANDBY_POS,
+            config.standby_time.bits()
+        );
+
+        Ok(ctrl_hum == config.humidity_oversampling.bits()
+            && ctrl_meas & (BME280_CTRL_PRESS_MSK | BME280_CTRL_TEMP_MSK) == expected_ctrl_meas
+            && config_reg & (BME280_FILTER_MSK | BME280_STANDBY_MSK) == expected_config)
+    }
+
+    /// Re-initializes the chip with the last applied configuration if it was lost
+    async fn restore_config<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        if !self.verify_config().await? {
+            self.recover(delay).await?;
+        }
+        Ok(())
+    }
+
+    /// Re-initializes the chip with the last applied configuration
+    async fn recover<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        let config = self.config;
+        let result = self.init(delay, config).await;
+        if result.is_err() {
+            // The soft reset may have gone through even though a later step failed. Keeping the
+            // configuration and marking the registers as stale makes a retry, or the next
+            // measurement, write all of them again instead of falling back to the reset state.
+            self.config = config;
+            self.in_flight = true;
+        }
+        result
+    }
+
+    /// Checks the chip ID and the calibration data, then validates a forced conversion against
+    /// the operating range of the sensor
+    async fn self_test<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.ensure_initialized()?;
+        self.verify_chip_id().await?;
+
+        // An erased or unreadable NVM reads as all zeros or all ones.
+        let pt_calib_data = self.read_pt_calib_data(BME280_P_T_CALIB_DATA_ADDR).await?;
+        let h_calib_data = if self.has_humidity() {
+            let h_calib_data = self.read_h_calib_data(BME280_H_CALIB_DATA_ADDR).await?;
+            if is_blank(&h_calib_data) {
+                return Err(Error::InvalidData);
+            }
+            h_calib_data
+        } else {
+            [0; BME280_H_CALIB_DATA_LEN]
+        };
+        if is_blank(&pt_calib_data) {
+            return Err(Error::InvalidData);
+        }
+        let mut calibration = parse_calib_data(&pt_calib_data, &h_calib_data);
+
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        let measurements: Measurements<I::Error> = Measurements::parse(data, &mut calibration)?;
+        if !measurements.in_range() {
+            return Err(Error::OutOfRange);
+        }
+        self.calibration = Some(calibration);
+        Ok(())
     }
 
-    fn mode(&mut self) -> Result<SensorMode, Error<I::Error>> {
-        let data = self.interface.read_register(BME280_PWR_CTRL_ADDR)?;
+    async fn mode(&mut self) -> Result<SensorMode, Error<I::Error>> {
+        let data = self.read_register(BME280_PWR_CTRL_ADDR).await?;
         match data & BME280_SENSOR_MODE_MSK {
             BME280_SLEEP_MODE => Ok(SensorMode::Sleep),
-            BME280_FORCED_MODE => Ok(SensorMode::Forced),
+            BME280_FORCED_MODE | BME280_FORCED_MODE_ALT => Ok(SensorMode::Forced),
             BME280_NORMAL_MODE => Ok(SensorMode::Normal),
             _ => Err(Error::InvalidData),
         }
     }
 
-    fn forced<D: DelayUs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
-        self.set_mode(BME280_FORCED_MODE, delay)
+    async fn forced(&mut self) -> Result<(), Error<I::Error>> {
+        self.set_mode(BME280_FORCED_MODE).await
+    }
+
+    async fn normal(&mut self) -> Result<(), Error<I::Error>> {
+        self.set_mode(BME280_NORMAL_MODE).await
+    }
+
+    async fn sleep(&mut self) -> Result<(), Error<I::Error>> {
+        self.write_mode(BME280_SLEEP_MODE).await
+    }
+
+    async fn wake(&mut self) -> Result<(), Error<I::Error>> {
+        self.write_mode(BME280_NORMAL_MODE).await
     }
 
-    fn set_mode<D: DelayUs>(&mut self, mode: u8, delay: &mut D) -> Result<(), Error<I::Error>> {
-        match self.mode()? {
+    async fn set_mode(&mut self, mode: u8) -> Result<(), Error<I::Error>> {
+        // Go through sleep mode rather than a soft reset when leaving normal mode, so that the
+        // oversampling and filter settings survive the transition.
+        match self.mode().await? {
             SensorMode::Sleep => {}
-            _ => self.soft_reset(delay)?,
+            _ => self.write_mode(BME280_SLEEP_MODE).await?,
         };
-        let data = self.interface.read_register(BME280_PWR_CTRL_ADDR)?;
+        self.write_mode(mode).await
+    }
+
+    async fn write_mode(&mut self, mode: u8) -> Result<(), Error<I::Error>> {
+        let data = self.read_register(BME280_PWR_CTRL_ADDR).await?;
         let data = set_bits!(data, BME280_SENSOR_MODE_MSK, 0, mode);
-        self.interface.write_register(BME280_PWR_CTRL_ADDR, data)
+        self.write_register(BME280_PWR_CTRL_ADDR, data).await
     }
 
     /// Captures and processes sensor data for temperature, pressure, and humidity
-    fn measure<D: DelayUs>(
+    async fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.measure_with_timeout(delay, BME280_MEASUREMENT_TIMEOUT_MS)
+            .await
+    }
+
+    /// Captures and processes sensor data, giving up after `timeout_ms` milliseconds
+    async fn measure_with_timeout<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        let data = self.convert(delay, timeout_ms).await?;
+        self.process(data)
+    }
+
+    /// Captures sensor data without compensating it
+    async fn measure_raw<D: DelayNs>(&mut self, delay: &mut D) -> Result<RawData, Error<I::Error>> {
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        Ok(RawData::parse(data))
+    }
+
+    /// Captures sensor data and processes it with the 32-bit integer compensation
+    #[cfg(feature = "integer")]
+    async fn measure_integer<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<IntegerMeasurements, Error<I::Error>> {
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let measurements = IntegerMeasurements::parse(data, calibration)?;
+        let channels = (
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity,
+        );
+        let (temperature, pressure, humidity) =
+            self.process_scaled(channels, [100.0, 1.0, 1000.0])?;
+        Ok(IntegerMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        })
+    }
+
+    /// Captures sensor data and processes it like the Bosch C API
+    #[cfg(feature = "integer")]
+    async fn measure_bosch<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<bosch::BoschMeasurements, Error<I::Error>> {
+        let data = RawData::parse(self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?);
+        let calibration = self.calibration.as_ref().ok_or(Error::NotInitialized)?;
+        let measurements = bosch::compensate(data, calibration);
+        let channels = (
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity,
+        );
+        // hundredths of a degree, Q24.8 pascals, and Q22.10 percent
+        let (temperature, pressure, humidity) =
+            self.process_scaled(channels, [100.0, 256.0, 1024.0])?;
+        Ok(bosch::BoschMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        })
+    }
+
+    /// Captures sensor data and processes it with the 32-bit integer compensation into
+    /// milli-units
+    #[cfg(feature = "integer")]
+    async fn measure_milli<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<MeasurementsMilli, Error<I::Error>> {
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let measurements = MeasurementsMilli::parse(data, calibration)?;
+        let channels = (
+            measurements.temperature_mc,
+            measurements.pressure_pa,
+            measurements.humidity_mpct,
+        );
+        let (temperature_mc, pressure_pa, humidity_mpct) =
+            self.process_scaled(channels, [1000.0, 1.0, 1000.0])?;
+        Ok(MeasurementsMilli {
+            temperature_mc,
+            pressure_pa,
+            humidity_mpct,
+        })
+    }
+
+    /// Captures sensor data and processes the pressure only with the 64-bit integer
+    /// compensation. The temperature is compensated as well, as the pressure compensation
+    /// depends on it.
+    #[cfg(feature = "integer")]
+    async fn measure_pressure_64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<u32>, Error<I::Error>> {
+        let data = RawData::parse(self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?);
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let temperature =
+            match IntegerMeasurements::compensate_temperature(data.temperature, calibration) {
+                Some(temperature) => temperature,
+                None => return Ok(None),
+            };
+        let pressure = IntegerMeasurements::compensate_pressure_64(data.pressure, calibration)?;
+        // hundredths of a degree and Q24.8 pascals, the temperature only being range checked
+        let channels = (Some(temperature), pressure, None);
+        let (_, pressure, _) = self.process_scaled(channels, [100.0, 256.0, 1024.0])?;
+        Ok(pressure)
+    }
+
+    /// Captures sensor data and processes it with the integer compensation into fixed-point
+    /// numbers
+    #[cfg(feature = "with_fixed")]
+    async fn measure_fixed<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<FixedMeasurements, Error<I::Error>> {
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let measurements = FixedMeasurements::parse(data, calibration)?;
+        let channels = (
+            measurements.temperature.map(I16F16::to_bits),
+            measurements.pressure.map(U24F8::to_bits),
+            measurements.humidity.map(U22F10::to_bits),
+        );
+        let (temperature, pressure, humidity) =
+            self.process_scaled(channels, [65536.0, 256.0, 1024.0])?;
+        Ok(FixedMeasurements {
+            temperature: temperature.map(I16F16::from_bits),
+            pressure: pressure.map(U24F8::from_bits),
+            humidity: humidity.map(U22F10::from_bits),
+        })
+    }
+
+    /// Captures and processes sensor data into typed quantities
+    #[cfg(feature = "uom")]
+    async fn measure_quantities<D: DelayNs>(
         &mut self,
         delay: &mut D,
+    ) -> Result<QuantityMeasurements, Error<I::Error>> {
+        self.measure(delay).await.map(QuantityMeasurements::from)
+    }
+
+    /// Captures sensor data and processes it with the double-precision compensation
+    #[cfg(feature = "f64")]
+    async fn measure_f64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<DoubleMeasurements, Error<I::Error>> {
+        let data = self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?;
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let measurements = DoubleMeasurements::parse(data, calibration)?;
+        self.check_range([
+            measurements.temperature as f32,
+            measurements.pressure as f32,
+            measurements
+                .humidity
+                .map_or(f32::NAN, |humidity| humidity as f32),
+        ])?;
+        Ok(self.offsets.apply_f64(measurements))
+    }
+
+    /// Performs `n` forced conversions and aggregates the results
+    async fn measure_n<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        n: u16,
+    ) -> Result<MeasurementStatistics<I::Error>, Error<I::Error>> {
+        let first = self.measure(delay).await?;
+        // An unavailable humidity is carried as NaN, which `min` and `max` ignore.
+        let mut sum = [
+            first.temperature,
+            first.pressure,
+            first.humidity.unwrap_or(f32::NAN),
+        ];
+        let mut min = sum;
+        let mut max = sum;
+        for _ in 1..n {
+            let measurements = self.measure(delay).await?;
+            let values = [
+                measurements.temperature,
+                measurements.pressure,
+                measurements.humidity.unwrap_or(f32::NAN),
+            ];
+            for (i, value) in values.iter().enumerate() {
+                sum[i] += value;
+                min[i] = min[i].min(*value);
+                max[i] = max[i].max(*value);
+            }
+        }
+
+        let count = n.max(1) as f32;
+        Ok(MeasurementStatistics {
+            mean: Measurements::from_channels(sum.map(|value| value / count)),
+            min: Measurements::from_channels(min),
+            max: Measurements::from_channels(max),
+        })
+    }
+
+    /// Captures sensor data and processes the temperature only
+    async fn measure_temperature<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I::Error>> {
+        let data = RawData::parse(self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?);
+        let calibration = self.calibration.as_ref().ok_or(Error::NotInitialized)?;
+        let temperature = compensate_temperature(data.temperature, calibration);
+        self.check_range([temperature, f32::NAN, f32::NAN])?;
+        Ok(self.offsets.temperature(temperature))
+    }
+
+    /// Captures sensor data and processes the pressure only.
+    /// The temperature is compensated as well, as the pressure compensation depends on it.
+    async fn measure_pressure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<I::Error>> {
+        let data = RawData::parse(self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?);
+        let calibration = self.calibration.as_ref().ok_or(Error::NotInitialized)?;
+        let pressure = compensate_pressure(data.pressure, data.temperature, calibration)?;
+        self.check_range([f32::NAN, pressure, f32::NAN])?;
+        Ok(self.offsets.pressure(pressure))
+    }
+
+    /// Captures sensor data and processes the humidity only.
+    /// The temperature is compensated as well, as the humidity compensation depends on it.
+    async fn measure_humidity<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<f32>, Error<I::Error>> {
+        let data = RawData::parse(self.convert(delay, BME280_MEASUREMENT_TIMEOUT_MS).await?);
+        let calibration = self.calibration.as_ref().ok_or(Error::NotInitialized)?;
+        let temperature = compensate_temperature(data.temperature, calibration);
+        let humidity = compensate_humidity(data.humidity, data.temperature, calibration);
+        self.check_range([temperature, f32::NAN, humidity.unwrap_or(f32::NAN)])?;
+        Ok(self.offsets.humidity(humidity, temperature))
+    }
+
+    /// Runs a forced conversion and returns the uncompensated sensor data
+    async fn convert<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        self.start_conversion(delay).await?;
+        // Wait for the typical conversion time, then poll the status register instead of
+        // waiting for the worst-case conversion time.
+        let typical_us = self.config.measurement_duration().typical_us;
+        delay.delay_us(typical_us).await;
+        self.wait_for_conversion(delay, typical_us / 1000, timeout_ms)
+            .await?;
+        self.finish_conversion().await
+    }
+
+    /// Polls the status register until the running conversion has finished, failing with
+    /// [`Error::Timeout`] once `timeout_ms` milliseconds have passed, `elapsed_ms` of which
+    /// before the call
+    async fn wait_for_conversion<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        mut elapsed_ms: u32,
+        timeout_ms: u32,
+    ) -> Result<(), Error<I::Error>> {
+        while self.status().await?.measuring {
+            if elapsed_ms >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+        Ok(())
+    }
+
+    /// Waits for a conversion started with `start_conversion` to finish, then reads and
+    /// processes its result
+    async fn read_triggered<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.ensure_initialized()?;
+        self.wait_for_conversion(delay, 0, BME280_MEASUREMENT_TIMEOUT_MS)
+            .await?;
+        self.read_finished().await
+    }
+
+    /// Reads and processes the result of a finished conversion started with `start_conversion`
+    async fn read_finished(&mut self) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.ensure_initialized()?;
+        let data = self.finish_conversion().await?;
+        self.process(data)
+    }
+
+    /// Triggers a forced conversion, first bringing the chip back into a known state after an
+    /// interrupted sequence and restoring a lost configuration if the check is enabled
+    async fn start_conversion<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.ensure_initialized()?;
+        if self.in_flight {
+            self.recover_interrupted(delay).await?;
+        }
+        if self.check_config {
+            self.restore_config(delay).await?;
+        }
+        self.in_flight = true;
+        self.forced().await
+    }
+
+    /// Reads the uncompensated data of a finished conversion, failing with
+    /// [`Error::SensorStuck`] if the sensor keeps returning the same data
+    async fn finish_conversion(&mut self) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        let data = self.read_data().await?;
+        self.in_flight = false;
+        if self.stuck.update(&data) {
+            return Err(Error::SensorStuck);
+        }
+        Ok(data)
+    }
+
+    /// Compensates the data of a conversion, then applies the range check and the offsets
+    fn process(
+        &mut self,
+        data: [u8; BME280_P_T_H_DATA_LEN],
     ) -> Result<Measurements<I::Error>, Error<I::Error>> {
-        self.forced(delay)?;
-        delay.delay_ms(40).map_err(|_| Error::Delay)?; // await measurement
-        let measurements = self.interface.read_data(BME280_DATA_ADDR)?;
-        match self.calibration.as_mut() {
-            Some(calibration) => {
-                let measurements = Measurements::parse(measurements, &mut *calibration)?;
-                Ok(measurements)
+        let calibration = self.calibration.as_mut().ok_or(Error::NotInitialized)?;
+        let measurements = Measurements::parse(data, calibration)?;
+        self.check_range([
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity.unwrap_or(f32::NAN),
+        ])?;
+        Ok(self.offsets.apply(measurements))
+    }
+
+    /// Brings the chip back into a known state after a measurement or configuration sequence
+    /// was cancelled or failed halfway, or after a soft reset through `write_raw_register`:
+    /// waits for a conversion or an NVM copy that may still be running and rewrites all
+    /// configuration registers.
+    async fn recover_interrupted<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<I::Error>> {
+        self.wait_for_conversion(delay, 0, BME280_MEASUREMENT_TIMEOUT_MS)
+            .await?;
+        self.wait_for_nvm_copy(delay).await?;
+        let config = self.config;
+        self.configure(config).await
+    }
+
+    async fn is_measuring(&mut self) -> Result<bool, Error<I::Error>> {
+        Ok(self.status().await?.measuring)
+    }
+
+    async fn status(&mut self) -> Result<Status, Error<I::Error>> {
+        let data = self.read_register(BME280_STATUS_ADDR).await?;
+        Ok(Status {
+            measuring: data & BME280_STATUS_MEASURING_MSK != 0,
+            im_update: data & BME280_STATUS_IM_UPDATE_MSK != 0,
+        })
+    }
+
+    /// Burst-reads the data registers.
+    /// The BMP280 lacks the humidity registers, so its humidity is reported as skipped.
+    async fn read_data(&mut self) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        let result = self.interface.read_data(BME280_DATA_ADDR).await;
+        let mut data = result.map_err(|e| e.during(Operation::Read(BME280_DATA_ADDR)))?;
+        if !self.has_humidity() {
+            data[6] = (BME280_HUMIDITY_SKIPPED >> 8) as u8;
+            data[7] = BME280_HUMIDITY_SKIPPED as u8;
+        }
+        Ok(data)
+    }
+
+    /// Burst-reads the data registers, making sure the result does not mix two samples.
+    /// In normal mode the registers are updated at the end of each conversion. Outside a
+    /// conversion they stay stable for far longer than a burst read takes, so a single read
+    /// suffices; otherwise the burst is repeated until two consecutive reads agree.
+    async fn read_consistent_data(
+        &mut self,
+    ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        if !self.status().await?.measuring {
+            return self.read_data().await;
+        }
+        let mut previous = self.read_data().await?;
+        for _ in 0..BME280_TORN_READ_RETRIES {
+            let data = self.read_data().await?;
+            if data == previous {
+                return Ok(data);
             }
-            None => Err(Error::NoCalibrationData),
+            previous = data;
+        }
+        Err(Error::InvalidData)
+    }
+
+    /// Reads and processes the most recent sample without triggering a new conversion
+    async fn read_latest(&mut self) -> Result<Measurements<I::Error>, Error<I::Error>> {
+        self.ensure_initialized()?;
+        let data = self.read_consistent_data().await?;
+        self.process(data)
+    }
+}
+
+impl<I> BME280Common<I>
+where
+    I: Interface,
+{
+    /// Starts a forced conversion to be collected with `read_measurement`
+    fn start_measurement<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
+        self.start_conversion(delay)?;
+        self.measurement_state = MeasurementState::Pending;
+        Ok(())
+    }
+
+    /// Returns the result of the pending conversion, or `WouldBlock` while it is running.
+    /// A conversion is started if none is pending.
+    fn read_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> nb::Result<Measurements<I::Error>, Error<I::Error>> {
+        if self.measurement_state == MeasurementState::Idle {
+            self.start_measurement(delay)?;
+            return Err(nb::Error::WouldBlock);
+        }
+        if self.is_measuring()? {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.measurement_state = MeasurementState::Idle;
+        let data = self.finish_conversion()?;
+        Ok(self.process(data)?)
+    }
+
+    /// Yields the samples produced in normal mode, waiting one output period between them
+    fn samples<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> impl Iterator<Item = Result<Measurements<I::Error>, Error<I::Error>>> + 'a {
+        Samples {
+            common: self,
+            delay,
         }
     }
 }
 
+#[cfg(feature = "async")]
+impl<I> AsyncBME280Common<I>
+where
+    I: AsyncInterface,
+{
+    /// Streams the samples produced in normal mode, waiting one output period between them
+    fn into_stream<D: AsyncDelayNs>(
+        self,
+        delay: D,
+    ) -> impl Stream<Item = Result<Measurements<I::Error>, Error<I::Error>>> {
+        stream::unfold((self, delay), |(mut common, mut delay)| async move {
+            delay.delay_us(common.config.output_period_us()).await;
+            let measurements = common.read_latest().await;
+            Some((measurements, (common, delay)))
+        })
+    }
+}
+
+/// Whether `data` reads as all zeros or all ones
+fn is_blank(data: &[u8]) -> bool {
+    data.iter().all(|&byte| byte == 0x00) || data.iter().all(|&byte| byte == 0xFF)
+}
+
+#[cfg_attr(not(feature = "humidity"), allow(unused_variables))]
 fn parse_calib_data(
     pt_data: &[u8; BME280_P_T_CALIB_DATA_LEN],
     h_data: &[u8; BME280_H_CALIB_DATA_LEN],
@@ -631,41 +3352,252 @@ fn parse_calib_data(
     let dig_t1 = concat_bytes!(pt_data[1], pt_data[0]);
     let dig_t2 = concat_bytes!(pt_data[3], pt_data[2]) as i16;
     let dig_t3 = concat_bytes!(pt_data[5], pt_data[4]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p1 = concat_bytes!(pt_data[7], pt_data[6]);
+    #[cfg(feature = "pressure")]
     let dig_p2 = concat_bytes!(pt_data[9], pt_data[8]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p3 = concat_bytes!(pt_data[11], pt_data[10]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p4 = concat_bytes!(pt_data[13], pt_data[12]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p5 = concat_bytes!(pt_data[15], pt_data[14]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p6 = concat_bytes!(pt_data[17], pt_data[16]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p7 = concat_bytes!(pt_data[19], pt_data[18]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p8 = concat_bytes!(pt_data[21], pt_data[20]) as i16;
+    #[cfg(feature = "pressure")]
     let dig_p9 = concat_bytes!(pt_data[23], pt_data[22]) as i16;
+    #[cfg(feature = "humidity")]
     let dig_h1 = pt_data[25];
+    #[cfg(feature = "humidity")]
     let dig_h2 = concat_bytes!(h_data[1], h_data[0]) as i16;
+    #[cfg(feature = "humidity")]
     let dig_h3 = h_data[2];
+    #[cfg(feature = "humidity")]
     let dig_h4 = (h_data[3] as i8 as i16 * 16) | ((h_data[4] as i8 as i16) & 0x0F);
+    #[cfg(feature = "humidity")]
     let dig_h5 = (h_data[5] as i8 as i16 * 16) | (((h_data[4] as i8 as i16) & 0xF0) >> 4);
+    #[cfg(feature = "humidity")]
     let dig_h6 = h_data[6] as i8;
 
     CalibrationData {
         dig_t1,
         dig_t2,
         dig_t3,
+        #[cfg(feature = "pressure")]
         dig_p1,
+        #[cfg(feature = "pressure")]
         dig_p2,
+        #[cfg(feature = "pressure")]
         dig_p3,
+        #[cfg(feature = "pressure")]
         dig_p4,
+        #[cfg(feature = "pressure")]
         dig_p5,
+        #[cfg(feature = "pressure")]
         dig_p6,
+        #[cfg(feature = "pressure")]
         dig_p7,
+        #[cfg(feature = "pressure")]
         dig_p8,
+        #[cfg(feature = "pressure")]
         dig_p9,
+        #[cfg(feature = "humidity")]
         dig_h1,
+        #[cfg(feature = "humidity")]
         dig_h2,
+        #[cfg(feature = "humidity")]
         dig_h3,
+        #[cfg(feature = "humidity")]
         dig_h4,
+        #[cfg(feature = "humidity")]
         dig_h5,
+        #[cfg(feature = "humidity")]
         dig_h6,
         t_fine: 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calibration register dump of a BME280
+    const CALIBRATION_DUMP: [u8; BME280_CALIB_DATA_LEN] = [
+        0x45, 0x6F, 0x6F, 0x68, 0x32, 0x00, 0xAE, 0x99, 0x70, 0xD5, 0xD0, 0x0B, 0xE6, 0x1C, 0x9C,
+        0xFF, 0xF9, 0xFF, 0xAC, 0x26, 0x0A, 0xD8, 0xBD, 0x10, 0x00, 0x4B, 0x6A, 0x01, 0x00, 0x13,
+        0x29, 0x03, 0x1E,
+    ];
+
+    #[test]
+    #[cfg(all(feature = "pressure", feature = "humidity"))]
+    fn calibration_bytes_round_trip() {
+        let calibration = CalibrationData::from_bytes(&CALIBRATION_DUMP);
+        assert_eq!(calibration.dig_t1(), 28485);
+        assert_eq!(calibration.dig_t2(), 26735);
+        assert_eq!(calibration.dig_t3(), 50);
+        assert_eq!(calibration.dig_p1(), 39342);
+        assert_eq!(calibration.dig_p2(), -10896);
+        assert_eq!(calibration.dig_p9(), 4285);
+        assert_eq!(calibration.dig_h1(), 75);
+        assert_eq!(calibration.dig_h2(), 362);
+        assert_eq!(calibration.dig_h3(), 0);
+        assert_eq!(calibration.dig_h4(), 313);
+        assert_eq!(calibration.dig_h5(), 50);
+        assert_eq!(calibration.dig_h6(), 30);
+
+        let bytes = calibration.to_bytes();
+        assert_eq!(bytes, CALIBRATION_DUMP);
+        let restored = CalibrationData::from_bytes(&bytes);
+        assert_eq!(restored.to_bytes(), CALIBRATION_DUMP);
+        assert_eq!(restored.dig_h4(), calibration.dig_h4());
+        assert_eq!(restored.dig_h5(), calibration.dig_h5());
+    }
+
+    #[test]
+    fn calibration_from_slice_checks_the_length() {
+        let calibration = CalibrationData::from_slice(&CALIBRATION_DUMP).unwrap();
+        assert_eq!(
+            calibration.to_bytes(),
+            CalibrationData::from_bytes(&CALIBRATION_DUMP).to_bytes()
+        );
+
+        assert!(CalibrationData::from_slice(&[]).is_none());
+        assert!(
+            CalibrationData::from_slice(&CALIBRATION_DUMP[..BME280_P_T_CALIB_DATA_LEN]).is_none()
+        );
+        assert!(
+            CalibrationData::from_slice(&CALIBRATION_DUMP[..BME280_CALIB_DATA_LEN - 1]).is_none()
+        );
+        let mut oversized = [0; BME280_CALIB_DATA_LEN + 1];
+        oversized[..BME280_CALIB_DATA_LEN].copy_from_slice(&CALIBRATION_DUMP);
+        assert!(CalibrationData::from_slice(&oversized).is_none());
+    }
+
+    #[test]
+    fn packed_measurements_round_trip() {
+        // resolution of 0.01 °C, 2 Pa, and 0.01 %RH, i.e. half a step after rounding
+        let measurements: Measurements<()> =
+            Measurements::from_channels([23.456, 100_321.3, 45.678]);
+        let unpacked: Measurements<()> = Measurements::unpack(&measurements.pack());
+        assert!((unpacked.temperature - 23.456).abs() <= 0.005);
+        assert!((unpacked.pressure - 100_321.3).abs() <= 1.0);
+        assert!((unpacked.humidity.unwrap() - 45.678).abs() <= 0.005);
+
+        // the limits of the operating range are exact, values beyond them are clamped
+        for [temperature, pressure, humidity] in [[-40.0, 30000.0, 0.0], [85.0, 110_000.0, 100.0]] {
+            let measurements: Measurements<()> =
+                Measurements::from_channels([temperature, pressure, humidity]);
+            let unpacked: Measurements<()> = Measurements::unpack(&measurements.pack());
+            assert_eq!(unpacked.temperature, temperature);
+            assert_eq!(unpacked.pressure, pressure);
+            assert_eq!(unpacked.humidity, Some(humidity));
+        }
+        let beyond: Measurements<()> = Measurements::from_channels([-50.0, 120_000.0, 150.0]);
+        let unpacked: Measurements<()> = Measurements::unpack(&beyond.pack());
+        assert_eq!(unpacked.temperature, -40.0);
+        assert_eq!(unpacked.pressure, 110_000.0);
+        assert_eq!(unpacked.humidity, Some(100.0));
+    }
+
+    #[test]
+    fn packed_measurements_without_humidity() {
+        let measurements: Measurements<()> =
+            Measurements::from_channels([21.5, 98_000.0, f32::NAN]);
+        assert_eq!(measurements.humidity, None);
+        let bytes = measurements.pack();
+        assert_eq!(bytes[4..6], u16::MAX.to_le_bytes());
+        let unpacked: Measurements<()> = Measurements::unpack(&bytes);
+        assert_eq!(unpacked.temperature, 21.5);
+        assert_eq!(unpacked.pressure, 98_000.0);
+        assert_eq!(unpacked.humidity, None);
+
+        let unavailable: Measurements<()> =
+            Measurements::from_channels([f32::NAN, f32::NAN, f32::NAN]);
+        let unpacked: Measurements<()> = Measurements::unpack(&unavailable.pack());
+        assert!(unpacked.temperature.is_nan());
+        assert!(unpacked.pressure.is_nan());
+        assert_eq!(unpacked.humidity, None);
+    }
+
+    #[test]
+    fn dew_and_frost_point_at_zero_humidity() {
+        let dry: Measurements<()> = Measurements::from_channels([20.0, 101325.0, 0.0]);
+        assert_eq!(dry.dew_point(), None);
+        assert_eq!(dry.frost_point(), None);
+        assert_eq!(dry.condensation_risk(-50.0, 1.0), Some(false));
+
+        let humid: Measurements<()> = Measurements::from_channels([20.0, 101325.0, 50.0]);
+        assert!(humid.dew_point().unwrap().is_finite());
+        assert!(humid.frost_point().unwrap().is_finite());
+    }
+
+    #[test]
+    #[cfg(feature = "integer")]
+    fn offsets_scale_to_integer_units() {
+        let offsets = Offsets {
+            temperature: 0.5,
+            pressure: -12.5,
+            humidity: 3.0,
+            self_heating: 0.0,
+        };
+        assert_eq!(
+            offsets.apply_scaled(
+                (Some(2150), Some(100_000), Some(50_000)),
+                [100.0, 1.0, 1000.0]
+            ),
+            (Some(2200), Some(99_987), Some(53_000))
+        );
+        // the humidity saturates at 100 %RH
+        assert_eq!(
+            offsets.apply_scaled((Some(2150), None, Some(99_000)), [100.0, 1.0, 1000.0]),
+            (Some(2200), None, Some(100_000))
+        );
+        assert_eq!(
+            Offsets::default().apply_scaled((None, Some(25_767_240), None), [100.0, 256.0, 1024.0]),
+            (None, Some(25_767_240), None)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "f64")]
+    fn double_offsets_keep_the_precision() {
+        let measurements = DoubleMeasurements {
+            temperature: 21.5,
+            pressure: 100_000.0,
+            humidity: Some(45.123_456_789),
+        };
+        let corrected = Offsets::default().apply_f64(measurements);
+        assert_eq!(corrected.humidity, measurements.humidity);
+    }
+
+    #[test]
+    fn stuck_detector_counts_identical_frames() {
+        let frame = [0x80; BME280_P_T_H_DATA_LEN];
+        let other = [0x81; BME280_P_T_H_DATA_LEN];
+        let mut detector = StuckDetector {
+            limit: 3,
+            ..StuckDetector::default()
+        };
+        assert!(!detector.update(&frame));
+        assert!(!detector.update(&frame));
+        assert!(detector.update(&frame));
+        assert!(detector.update(&frame));
+
+        // a different frame or a reset starts over
+        assert!(!detector.update(&other));
+        assert!(!detector.update(&other));
+        detector.reset();
+        assert!(!detector.update(&other));
+        assert!(!detector.update(&other));
+        assert!(detector.update(&other));
+
+        let mut disabled = StuckDetector::default();
+        for _ in 0..10 {
+            assert!(!disabled.update(&frame));
+        }
+    }
+}
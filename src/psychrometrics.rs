@@ -0,0 +1,35 @@
+//! Psychrometric properties of moist air, after the ASHRAE Handbook of Fundamentals.
+//!
+//! The functions take the `temperature` in degrees celsius, the `pressure` in pascals, and the
+//! relative `humidity` in percent, like the measurements.
+//!
+//! ```
+//! use bme280::psychrometrics;
+//!
+//! let enthalpy = psychrometrics::enthalpy(20.0, 101325.0, 50.0);
+//! assert!((enthalpy - 38.5).abs() < 0.5);
+//! ```
+
+use super::derived;
+
+/// Ratio of the molar masses of water and dry air
+const MOLAR_MASS_RATIO: f32 = 0.621945;
+
+/// Mixing ratio in kilograms of water vapour per kilogram of dry air
+pub fn mixing_ratio(temperature: f32, pressure: f32, humidity: f32) -> f32 {
+    let vapor_pressure = derived::vapor_pressure(temperature, humidity);
+    MOLAR_MASS_RATIO * vapor_pressure / (pressure - vapor_pressure)
+}
+
+/// Specific humidity in kilograms of water vapour per kilogram of moist air
+pub fn specific_humidity(temperature: f32, pressure: f32, humidity: f32) -> f32 {
+    let mixing_ratio = mixing_ratio(temperature, pressure, humidity);
+    mixing_ratio / (1.0 + mixing_ratio)
+}
+
+/// Specific enthalpy in kilojoules per kilogram of dry air, relative to dry air and liquid water
+/// at 0 °C
+pub fn enthalpy(temperature: f32, pressure: f32, humidity: f32) -> f32 {
+    let mixing_ratio = mixing_ratio(temperature, pressure, humidity);
+    1.006 * temperature + mixing_ratio * (2501.0 + 1.86 * temperature)
+}
@@ -0,0 +1,255 @@
+//! Retrying of register accesses that fail on the bus.
+//!
+//! [`Retry`] wraps an [`Interface`] so that transient bus errors, such as an occasional NACK on
+//! a long I²C cable, are retried with a backoff instead of aborting the operation. It is used with
+//! the [`custom`](crate::custom) driver, on top of either [`I2CInterface`] or [`SPIInterface`]:
+//!
+//! ```no_run
+//! # use embedded_hal::{delay::DelayNs, i2c::I2c, spi::SpiDevice};
+//! # fn demo<I2C: I2c, SPI: SpiDevice, D: DelayNs + Clone>(i2c: I2C, spi: SPI, delay: D) {
+//! use bme280::custom::BME280;
+//! use bme280::i2c::I2CInterface;
+//! use bme280::retry::{Retry, RetryPolicy};
+//! use bme280::spi::SPIInterface;
+//!
+//! let policy = RetryPolicy::default();
+//! let i2c_interface = Retry::new(I2CInterface::new(i2c, 0x76), delay.clone(), policy);
+//! let mut i2c_bme280 = BME280::new(i2c_interface);
+//! let spi_interface = Retry::new(SPIInterface::new(spi), delay, policy);
+//! let mut spi_bme280 = BME280::new(spi_interface);
+//! # }
+//! ```
+//!
+//! [`I2CInterface`]: crate::i2c::I2CInterface
+//! [`SPIInterface`]: crate::spi::SPIInterface
+
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+#[cfg(feature = "async")]
+use super::AsyncInterface;
+use super::{
+    Error, Interface, BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN, BME280_P_T_H_DATA_LEN,
+};
+
+/// Number of retries and backoff between them.
+/// The default retries three times, after 100µs, 200µs, and 400µs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    retries: u8,
+    backoff_us: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            retries: 3,
+            backoff_us: 100,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sets the number of retries after the first attempt.
+    pub fn with_retries(mut self, retries: u8) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the wait before the first retry, in microseconds. It doubles with each retry.
+    pub fn with_backoff_us(mut self, backoff_us: u32) -> Self {
+        self.backoff_us = backoff_us;
+        self
+    }
+}
+
+/// [`Interface`] retrying the register accesses of another one on bus errors
+#[derive(Debug)]
+pub struct Retry<I, D> {
+    interface: I,
+    delay: D,
+    policy: RetryPolicy,
+}
+
+impl<I, D> Retry<I, D> {
+    /// Wrap the `interface`, waiting on the `delay` between retries
+    pub fn new(interface: I, delay: D, policy: RetryPolicy) -> Self {
+        Retry {
+            interface,
+            delay,
+            policy,
+        }
+    }
+
+    /// Release the wrapped interface and the delay
+    pub fn into_inner(self) -> (I, D) {
+        (self.interface, self.delay)
+    }
+}
+
+/// Waits before the next attempt, or returns the `error` if it is not a bus error or the
+/// retries of the `policy` are exhausted
+#[maybe_async_cfg::maybe(
+    idents(DelayNs(sync = "DelayNs", async = "AsyncDelayNs")),
+    sync(keep_self),
+    async(feature = "async", self = "backoff_async")
+)]
+async fn backoff<E, D: DelayNs>(
+    delay: &mut D,
+    policy: &RetryPolicy,
+    attempt: &mut u8,
+    error: Error<E>,
+) -> Result<(), Error<E>> {
+    match error {
+        Error::Bus(_) | Error::BusOperation { .. } if *attempt < policy.retries => {
+            let backoff_us = policy.backoff_us.saturating_mul(1 << (*attempt).min(31));
+            delay.delay_us(backoff_us).await;
+            *attempt += 1;
+            Ok(())
+        }
+        error => Err(error),
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync = "Interface", async = "AsyncInterface"),
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs"),
+        backoff(sync = "backoff", async = "backoff_async")
+    ),
+    sync(keep_self),
+    async(feature = "async", keep_self)
+)]
+impl<I, D> Interface for Retry<I, D>
+where
+    I: Interface,
+    D: DelayNs,
+{
+    type Error = I::Error;
+
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.interface.read_register(register).await {
+                Err(e) => backoff(&mut self.delay, &self.policy, &mut attempt, e).await?,
+                result => return result,
+            }
+        }
+    }
+
+    async fn read_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.interface.read_data(register).await {
+                Err(e) => backoff(&mut self.delay, &self.policy, &mut attempt, e).await?,
+                result => return result,
+            }
+        }
+    }
+
+    async fn read_pt_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.interface.read_pt_calib_data(register).await {
+                Err(e) => backoff(&mut self.delay, &self.policy, &mut attempt, e).await?,
+                result => return result,
+            }
+        }
+    }
+
+    async fn read_h_calib_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.interface.read_h_calib_data(register).await {
+                Err(e) => backoff(&mut self.delay, &self.policy, &mut attempt, e).await?,
+                result => return result,
+            }
+        }
+    }
+
+    async fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.interface.write_register(register, payload).await {
+                Err(e) => backoff(&mut self.delay, &self.policy, &mut attempt, e).await?,
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Delay recording the waits, in microseconds
+    #[derive(Default)]
+    struct RecordingDelay {
+        waits_us: [u32; 4],
+        count: usize,
+    }
+
+    impl DelayNs for RecordingDelay {
+        fn delay_ns(&mut self, ns: u32) {
+            self.delay_us(ns / 1000);
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            self.waits_us[self.count] = us;
+            self.count += 1;
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_until_the_retries_are_exhausted() {
+        let policy = RetryPolicy::default();
+        let mut delay = RecordingDelay::default();
+        let mut attempt = 0;
+        for _ in 0..3 {
+            assert!(backoff(&mut delay, &policy, &mut attempt, Error::Bus(())).is_ok());
+        }
+        let error = Error::BusOperation {
+            operation: crate::Operation::Read(0xF7),
+            error: (),
+        };
+        let result = backoff(&mut delay, &policy, &mut attempt, error);
+        assert!(matches!(result, Err(Error::BusOperation { .. })));
+        assert_eq!(attempt, 3);
+        assert_eq!(delay.waits_us[..delay.count], [100, 200, 400]);
+    }
+
+    #[test]
+    fn backoff_saturates() {
+        let policy = RetryPolicy::default()
+            .with_retries(u8::MAX)
+            .with_backoff_us(1);
+        let mut delay = RecordingDelay::default();
+        // the shift is capped at 31 bits
+        let mut attempt = 40;
+        assert!(backoff(&mut delay, &policy, &mut attempt, Error::Bus(())).is_ok());
+        let policy = policy.with_backoff_us(4);
+        assert!(backoff(&mut delay, &policy, &mut attempt, Error::Bus(())).is_ok());
+        assert_eq!(delay.waits_us[..delay.count], [1 << 31, u32::MAX]);
+    }
+
+    #[test]
+    fn backoff_does_not_retry_other_errors() {
+        let policy = RetryPolicy::default();
+        let mut delay = RecordingDelay::default();
+        let mut attempt = 0;
+        let result = backoff::<(), _>(&mut delay, &policy, &mut attempt, Error::Timeout);
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert_eq!(attempt, 0);
+        assert_eq!(delay.count, 0);
+    }
+}
@@ -0,0 +1,56 @@
+//! Implementations of the [`embedded-sensors-hal`](https://docs.rs/embedded-sensors-hal) traits.
+//!
+//! The traits take no delay, so the blocking driver is paired with one in a [`Sensor`]:
+//!
+//! ```no_run
+//! # fn demo<I2C: embedded_hal::i2c::I2c, D: embedded_hal::delay::DelayNs>(i2c: I2C, delay: D) {
+//! use bme280::i2c::BME280;
+//! use bme280::sensors::Sensor;
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! let mut sensor = Sensor::new(BME280::new_primary(i2c), delay);
+//! let temperature = sensor.temperature();
+//! # }
+//! ```
+//!
+//! Both traits perform a forced conversion. The humidity fails with [`Error::InvalidData`] if
+//! the chip has no humidity sensor or the humidity measurement is skipped.
+
+use core::fmt::Debug;
+
+use embedded_sensors_hal::sensor::ErrorKind;
+
+use super::Error;
+
+/// Blocking BME280 driver together with the delay its conversions wait on
+#[derive(Debug)]
+pub struct Sensor<DEV, D> {
+    pub(crate) device: DEV,
+    pub(crate) delay: D,
+}
+
+impl<DEV, D> Sensor<DEV, D> {
+    /// Pair the initialized `device` with the `delay`
+    pub fn new(device: DEV, delay: D) -> Self {
+        Sensor { device, delay }
+    }
+
+    /// Release the driver and the delay
+    pub fn into_inner(self) -> (DEV, D) {
+        (self.device, self.delay)
+    }
+}
+
+impl<E: Debug> embedded_sensors_hal::sensor::Error for Error<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Bus(_) | Error::BusOperation { .. } => ErrorKind::Peripheral,
+            Error::NoCalibrationData | Error::NotInitialized | Error::Timeout => {
+                ErrorKind::NotReady
+            }
+            Error::OutOfRange => ErrorKind::Saturated,
+            Error::SensorStuck => ErrorKind::Peripheral,
+            _ => ErrorKind::Other,
+        }
+    }
+}
@@ -1,139 +1,856 @@
 //! BME280 driver for sensors attached via SPI.
 
-use embedded_hal::delay::DelayUs;
+#[cfg(feature = "embassy")]
+use embassy_embedded_hal::shared_bus::asynch::spi::SpiDevice as SharedSpiDevice;
+#[cfg(feature = "embassy")]
+use embassy_sync::{blocking_mutex::raw::RawMutex, mutex::Mutex};
+use embedded_hal::delay::DelayNs;
+#[cfg(any(feature = "embassy", feature = "embedded-hal-bus"))]
+use embedded_hal::digital::OutputPin;
+#[cfg(feature = "embedded-hal-bus")]
 use embedded_hal::spi::SpiBus;
-use embedded_hal::spi::SpiDevice;
+use embedded_hal::spi::{Operation, SpiDevice};
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+#[cfg(any(
+    feature = "embassy",
+    all(feature = "async", feature = "embedded-hal-bus")
+))]
+use embedded_hal_async::spi::SpiBus as AsyncSpiBus;
+#[cfg(feature = "async")]
+use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
+#[cfg(feature = "embedded-hal-bus")]
+use embedded_hal_bus::spi::{ExclusiveDevice, NoDelay};
+#[cfg(feature = "async")]
+use futures_util::Stream;
 
+#[cfg(feature = "integer")]
+use super::bosch::BoschMeasurements;
+#[cfg(feature = "embedded-sensors-hal")]
+use super::sensors;
+use super::traits;
+use super::typestate::{self, Forced, Normal, Sleep, Transition};
+#[cfg(feature = "f64")]
+use super::DoubleMeasurements;
+#[cfg(feature = "with_fixed")]
+use super::FixedMeasurements;
+#[cfg(feature = "uom")]
+use super::QuantityMeasurements;
+#[cfg(feature = "async")]
+use super::{AsyncBME280Common, AsyncInterface};
 use super::{
-    BME280Common, Configuration, Error, IIRFilter, Interface, Measurements, Oversampling,
-    BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN, BME280_P_T_H_DATA_LEN,
+    BME280Common, CalibrationData, ChipVariant, Configuration, Error, IIRFilter, Interface,
+    MeasurementStatistics, Measurements, Offsets, Oversampling, RawData, SensorMode,
+    BME280_CHIP_ID_ADDR, BME280_CONFIG_ADDR, BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN,
+    BME280_P_T_H_DATA_LEN, BME280_RESET_ADDR, BME280_SOFT_RESET_CMD, BME280_SPI3W_EN_MSK,
 };
+#[cfg(feature = "integer")]
+use super::{IntegerMeasurements, MeasurementsMilli};
 
+#[maybe_async_cfg::maybe(
+    idents(BME280Common(sync = "BME280Common", async = "AsyncBME280Common")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
 /// Representation of a BME280
 #[derive(Debug, Default)]
 pub struct BME280<SPI> {
     common: BME280Common<SPIInterface<SPI>>,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        DelayNs(sync = "DelayNs", async = "AsyncDelayNs"),
+        SpiDevice(sync = "SpiDevice", async = "AsyncSpiDevice")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncBME280")
+)]
 impl<SPI, SPIE> BME280<SPI>
 where
     SPI: SpiDevice<Error = SPIE>,
-    SPI::Bus: SpiBus,
 {
-    /// Create a new BME280 struct
-    pub fn new(spi: SPI) -> Result<Self, Error<SPI>> {
-        Ok(BME280 {
-            common: BME280Common {
-                interface: SPIInterface { spi },
-                calibration: None,
-            },
-        })
+    /// Releases the SPI device, e.g. to repurpose it, or to power-gate the sensor and create the
+    /// driver again later. The sensor is left in whatever mode it is currently in.
+    pub fn release(self) -> SPI {
+        self.common.interface.spi
     }
 
     /// Initializes the BME280.
     /// This configures 2x temperature oversampling, 16x pressure oversampling, and the IIR filter
     /// coefficient 16.
-    pub fn init<D: DelayUs>(&mut self, delay: &mut D) -> Result<(), Error<SPIError<SPIE>>> {
-        self.common.init(
-            delay,
-            Configuration::default()
-                .with_humidity_oversampling(Oversampling::Oversampling1X)
-                .with_pressure_oversampling(Oversampling::Oversampling16X)
-                .with_temperature_oversampling(Oversampling::Oversampling2X)
-                .with_iir_filter(IIRFilter::Coefficient16),
-        )
+    pub async fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common
+            .init(
+                delay,
+                Configuration::default()
+                    .with_humidity_oversampling(Oversampling::Oversampling1X)
+                    .with_pressure_oversampling(Oversampling::Oversampling16X)
+                    .with_temperature_oversampling(Oversampling::Oversampling2X)
+                    .with_iir_filter(IIRFilter::Coefficient16),
+            )
+            .await
     }
 
     /// Initializes the BME280, applying the given configuration.
-    pub fn init_with_config<D: DelayUs>(
+    /// See [Initialization](crate#initialization).
+    pub async fn init_with_config<D: DelayNs>(
         &mut self,
         delay: &mut D,
         config: Configuration,
     ) -> Result<(), Error<SPIError<SPIE>>> {
-        self.common.init(delay, config)
+        self.common.init(delay, config).await
+    }
+
+    /// Soft-resets the BME280 and reloads its calibration data.
+    /// See [Initialization](crate#initialization).
+    pub async fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.reset(delay).await
+    }
+
+    /// Heals the BME280 after a bus fault or a brown-out, see [Recovery](crate#recovery).
+    pub async fn recover<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.recover(delay).await
+    }
+
+    /// Runs a self test of the initialized BME280, see [Self test](crate#self-test).
+    pub async fn self_test<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.self_test(delay).await
+    }
+
+    /// Applies a new configuration without a full re-initialization.
+    /// See [Configuration](crate#configuration).
+    pub async fn apply_config(
+        &mut self,
+        config: Configuration,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.apply_config(config).await
+    }
+
+    /// Changes the oversampling settings, keeping the rest of the configuration.
+    pub async fn set_oversampling(
+        &mut self,
+        temperature: Oversampling,
+        pressure: Oversampling,
+        humidity: Oversampling,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common
+            .set_oversampling(temperature, pressure, humidity)
+            .await
+    }
+
+    /// Changes the IIR filter setting, keeping the rest of the configuration.
+    pub async fn set_iir_filter(&mut self, filter: IIRFilter) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.set_iir_filter(filter).await
+    }
+
+    /// Checks whether the BME280 still holds the configuration applied by the last `init`.
+    /// Returns `false` if the registers were reverted, e.g. by a brown-out of the sensor.
+    pub async fn verify_config(&mut self) -> Result<bool, Error<SPIError<SPIE>>> {
+        self.common.verify_config().await
+    }
+
+    /// Reads the register at `address`, for registers the rest of the API does not cover
+    pub async fn read_register(&mut self, address: u8) -> Result<u8, Error<SPIError<SPIE>>> {
+        self.common.read_register(address).await
+    }
+
+    /// Writes `value` to the register at `address`, for registers the rest of the API does not
+    /// cover. See [Configuration](crate#configuration).
+    pub async fn write_register(
+        &mut self,
+        address: u8,
+        value: u8,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.write_raw_register(address, value).await
+    }
+
+    /// Enables or disables verifying the configuration before each forced measurement.
+    /// See [Recovery](crate#recovery).
+    pub fn set_config_check(&mut self, enabled: bool) {
+        self.common.check_config = enabled;
+    }
+
+    /// Enables or disables the range check of the compensated measurements.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_range_check(&mut self, enabled: bool) {
+        self.common.check_range = enabled;
+    }
+
+    /// Enables the detection of a hung sensor after `frames` identical conversions.
+    /// See [Plausibility checks](crate#plausibility-checks).
+    pub fn set_stuck_detection(&mut self, frames: u8) {
+        self.common.stuck.limit = frames;
+    }
+
+    /// Returns the chip variant identified by `init`, or `None` if the chip ID has not been read
+    /// yet. An unrecognized chip ID is reported even though `init` fails for it.
+    pub fn variant(&self) -> Option<ChipVariant> {
+        self.common.variant
+    }
+
+    /// Sets offsets that are added to the measurements after compensation.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_offsets(&mut self, temperature: f32, pressure: f32, humidity: f32) {
+        self.common.offsets = Offsets {
+            temperature,
+            pressure,
+            humidity,
+            ..self.common.offsets
+        };
+    }
+
+    /// Compensates the self-heating of a sensor that reads `offset` degrees celsius too high.
+    /// See [Trim offsets](crate#trim-offsets).
+    pub fn set_self_heating(&mut self, offset: f32) {
+        self.common.offsets.self_heating = offset;
+    }
+
+    /// Returns the calibration coefficients read by `init`, or `None` before initialization
+    pub fn calibration(&self) -> Option<&CalibrationData> {
+        self.common.calibration.as_ref()
+    }
+
+    /// Makes `init` accept the non-standard chip ID of a compatible clone part
+    pub fn expect_chip_id(&mut self, chip_id: u8, compatible: ChipVariant) {
+        self.common.chip_id_override = Some((chip_id, compatible));
     }
 
     /// Captures and processes sensor data for temperature, pressure, and humidity
-    pub fn measure<D: DelayUs>(
+    pub async fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.common.measure(delay).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity, returning
+    /// [`Error::Timeout`] if the conversion does not finish within `timeout_ms` milliseconds
+    pub async fn measure_with_timeout<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.common.measure_with_timeout(delay, timeout_ms).await
+    }
+
+    /// Captures sensor data and returns the uncompensated ADC values, e.g. to log raw frames
+    /// and compensate them later
+    pub async fn measure_raw<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RawData, Error<SPIError<SPIE>>> {
+        self.common.measure_raw(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation formulas of the
+    /// datasheet, which avoid floating point on targets without an FPU
+    #[cfg(feature = "integer")]
+    pub async fn measure_integer<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<IntegerMeasurements, Error<SPIError<SPIE>>> {
+        self.common.measure_integer(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation of the Bosch C API,
+    /// giving bit-identical results in its units
+    #[cfg(feature = "integer")]
+    pub async fn measure_bosch<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<BoschMeasurements, Error<SPIError<SPIE>>> {
+        self.common.measure_bosch(delay).await
+    }
+
+    /// Captures and processes sensor data with the 32-bit integer compensation, in
+    /// thousandths of a degree celsius, pascals, and thousandths of a percent relative humidity
+    #[cfg(feature = "integer")]
+    pub async fn measure_milli<D: DelayNs>(
         &mut self,
         delay: &mut D,
+    ) -> Result<MeasurementsMilli, Error<SPIError<SPIE>>> {
+        self.common.measure_milli(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only with the 64-bit integer
+    /// compensation, in 1/256 pascals (Q24.8 format)
+    #[cfg(feature = "integer")]
+    pub async fn measure_pressure_64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<u32>, Error<SPIError<SPIE>>> {
+        self.common.measure_pressure_64(delay).await
+    }
+
+    /// Captures and processes sensor data with the integer compensation, returning fixed-point
+    /// numbers for code that avoids floating point
+    #[cfg(feature = "with_fixed")]
+    pub async fn measure_fixed<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<FixedMeasurements, Error<SPIError<SPIE>>> {
+        self.common.measure_fixed(delay).await
+    }
+
+    /// Captures and processes sensor data into typed quantities of the `uom` crate
+    #[cfg(feature = "uom")]
+    pub async fn measure_quantities<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<QuantityMeasurements, Error<SPIError<SPIE>>> {
+        self.common.measure_quantities(delay).await
+    }
+
+    /// Captures and processes sensor data with the double-precision floating point
+    /// compensation formulas of the datasheet
+    #[cfg(feature = "f64")]
+    pub async fn measure_f64<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<DoubleMeasurements, Error<SPIError<SPIE>>> {
+        self.common.measure_f64(delay).await
+    }
+
+    /// Performs `n` forced conversions and returns the mean, minimum, and maximum of each
+    /// channel
+    pub async fn measure_n<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        n: u16,
+    ) -> Result<MeasurementStatistics<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.common.measure_n(delay, n).await
+    }
+
+    /// Captures sensor data and processes the temperature only, in degrees celsius
+    pub async fn measure_temperature<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<SPIError<SPIE>>> {
+        self.common.measure_temperature(delay).await
+    }
+
+    /// Captures sensor data and processes the pressure only, in pascals
+    pub async fn measure_pressure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<SPIError<SPIE>>> {
+        self.common.measure_pressure(delay).await
+    }
+
+    /// Captures sensor data and processes the relative humidity only, in percent.
+    /// Returns `None` if the chip has no humidity sensor or the humidity measurement is skipped.
+    pub async fn measure_humidity<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<f32>, Error<SPIError<SPIE>>> {
+        self.common.measure_humidity(delay).await
+    }
+
+    /// Puts the BME280 into normal mode, in which it cycles between conversions and standby
+    /// periods on its own. Use [`read_latest`](Self::read_latest) to fetch the samples.
+    pub async fn set_normal_mode(&mut self) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.normal().await
+    }
+
+    /// Reads the current operating mode back from the device.
+    pub async fn mode(&mut self) -> Result<SensorMode, Error<SPIError<SPIE>>> {
+        self.common.mode().await
+    }
+
+    /// Puts the BME280 into sleep mode.
+    /// Only the mode bits are changed, so the configuration is retained while sleeping.
+    pub async fn sleep(&mut self) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.sleep().await
+    }
+
+    /// Wakes the BME280 from sleep mode by putting it back into normal mode.
+    /// Only the mode bits are changed, so measurements resume with the existing configuration.
+    pub async fn wake(&mut self) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.wake().await
+    }
+
+    /// Triggers a single conversion in forced mode and returns without waiting for it.
+    /// Collect the result with [`read_triggered`](Self::read_triggered).
+    pub async fn trigger<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.start_conversion(delay).await
+    }
+
+    /// Waits for the conversion started with [`trigger`](Self::trigger) to finish, then reads
+    /// and processes its result.
+    pub async fn read_triggered<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.common.read_triggered(delay).await
+    }
+
+    /// Returns whether a conversion is currently running, based on the status register.
+    /// This is a cheap way to check whether a triggered conversion has finished.
+    pub async fn is_measuring(&mut self) -> Result<bool, Error<SPIError<SPIE>>> {
+        self.common.is_measuring().await
+    }
+
+    /// Reads and processes the most recent sample without triggering a new conversion.
+    /// This is intended for normal mode, where the sensor keeps its data registers up to date.
+    pub async fn read_latest(
+        &mut self,
     ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
-        self.common.measure(delay)
+        self.common.read_latest().await
     }
 }
 
-/// Register access functions for SPI
+impl<SPI, SPIE> BME280<SPI>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    /// Create a new BME280 struct
+    pub fn new(spi: SPI) -> Result<Self, Error<SPI>> {
+        Ok(BME280 {
+            common: BME280Common::new(SPIInterface::new(spi)),
+        })
+    }
+
+    /// Create a new BME280 struct for a sensor wired in 3-wire mode, with SDI and SDO shared.
+    /// `spi` needs to perform half-duplex transfers on the shared data line.
+    pub fn new_three_wire(spi: SPI) -> Self {
+        BME280 {
+            common: BME280Common::new(SPIInterface::new_three_wire(spi)),
+        }
+    }
+
+    /// Returns an endless iterator over the samples of a BME280 running in normal mode.
+    /// See [Normal mode](crate#normal-mode).
+    pub fn samples<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> impl Iterator<Item = Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>>> + 'a
+    {
+        self.common.samples(delay)
+    }
+
+    /// Puts the BME280 into sleep mode and wraps the driver in the [`typestate`] API,
+    /// handing the driver back together with the error on failure
+    #[allow(clippy::type_complexity)]
+    pub fn into_typestate(
+        mut self,
+    ) -> Result<typestate::BME280<Self, Sleep>, (Self, Error<SPIError<SPIE>>)> {
+        match self.sleep() {
+            Ok(()) => Ok(typestate::BME280::new(self)),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Starts a forced conversion without blocking.
+    /// Poll [`read_measurement`](Self::read_measurement) to collect the result.
+    pub fn start_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPIError<SPIE>>> {
+        self.common.start_measurement(delay)
+    }
+
+    /// Returns the result of the conversion started with
+    /// [`start_measurement`](Self::start_measurement), or `nb::Error::WouldBlock` while it runs.
+    pub fn read_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> nb::Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.common.read_measurement(delay)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI, SPIE> AsyncBME280<SPI>
+where
+    SPI: AsyncSpiDevice<Error = SPIE>,
+{
+    /// Create a new AsyncBME280 struct
+    pub fn new(spi: SPI) -> Self {
+        AsyncBME280 {
+            common: AsyncBME280Common::new(SPIInterface::new(spi)),
+        }
+    }
+
+    /// Create a new AsyncBME280 struct for a sensor wired in 3-wire mode, with SDI and SDO
+    /// shared. `spi` needs to perform half-duplex transfers on the shared data line.
+    pub fn new_three_wire(spi: SPI) -> Self {
+        AsyncBME280 {
+            common: AsyncBME280Common::new(SPIInterface::new_three_wire(spi)),
+        }
+    }
+
+    /// Turns the driver into an endless stream over the samples of a BME280 running in normal
+    /// mode. See [Normal mode](crate#normal-mode).
+    pub fn into_stream<D: AsyncDelayNs>(
+        self,
+        delay: D,
+    ) -> impl Stream<Item = Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>>> {
+        self.common.into_stream(delay)
+    }
+}
+
+#[cfg(feature = "embassy")]
+impl<'a, M, BUS, CS> AsyncBME280<SharedSpiDevice<'a, M, BUS, CS>>
+where
+    M: RawMutex,
+    BUS: AsyncSpiBus,
+    CS: OutputPin,
+{
+    /// Create a new AsyncBME280 struct on an SPI bus shared through an `embassy_sync` mutex,
+    /// selecting the sensor with the `cs` pin
+    pub fn new_shared(bus: &'a Mutex<M, BUS>, cs: CS) -> Self {
+        Self::new(SharedSpiDevice::new(bus, cs))
+    }
+}
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<'a, BUS, CS> BME280<ExclusiveDevice<&'a mut BUS, &'a mut CS, NoDelay>>
+where
+    BUS: SpiBus,
+    CS: OutputPin,
+{
+    /// Create a new BME280 struct on an SPI bus used by this sensor only, selecting the sensor
+    /// with the `cs` pin. The bus and the pin are borrowed, as `ExclusiveDevice` cannot hand
+    /// them back, and can be used again once the driver is dropped. Fails if the `cs` pin cannot
+    /// be driven high.
+    pub fn new_exclusive(bus: &'a mut BUS, cs: &'a mut CS) -> Result<Self, CS::Error> {
+        Ok(BME280 {
+            common: BME280Common::new(SPIInterface::new(ExclusiveDevice::new_no_delay(bus, cs)?)),
+        })
+    }
+}
+
+#[cfg(all(feature = "async", feature = "embedded-hal-bus"))]
+impl<'a, BUS, CS> AsyncBME280<ExclusiveDevice<&'a mut BUS, &'a mut CS, NoDelay>>
+where
+    BUS: AsyncSpiBus,
+    CS: OutputPin,
+{
+    /// Create a new AsyncBME280 struct on an SPI bus used by this sensor only, selecting the
+    /// sensor with the `cs` pin. The bus and the pin are borrowed, as `ExclusiveDevice` cannot
+    /// hand them back, and can be used again once the driver is dropped. Fails if the `cs` pin
+    /// cannot be driven high.
+    pub fn new_exclusive(bus: &'a mut BUS, cs: &'a mut CS) -> Result<Self, CS::Error> {
+        Ok(AsyncBME280 {
+            common: AsyncBME280Common::new(SPIInterface::new(ExclusiveDevice::new_no_delay(
+                bus, cs,
+            )?)),
+        })
+    }
+}
+
+impl<SPI, SPIE> typestate::BME280<BME280<SPI>, Sleep>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    /// Captures and processes sensor data for temperature, pressure, and humidity
+    pub fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.device.measure(delay)
+    }
+
+    /// Triggers a single conversion in forced mode.
+    pub fn trigger<D: DelayNs>(
+        self,
+        delay: &mut D,
+    ) -> Transition<BME280<SPI>, Sleep, Forced, Error<SPIError<SPIE>>> {
+        self.transition(|device| device.trigger(delay))
+    }
+
+    /// Puts the BME280 into normal mode.
+    pub fn into_normal(self) -> Transition<BME280<SPI>, Sleep, Normal, Error<SPIError<SPIE>>> {
+        self.transition(|device| device.set_normal_mode())
+    }
+}
+
+impl<SPI, SPIE> typestate::BME280<BME280<SPI>, Forced>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    /// Returns whether the triggered conversion is still running.
+    pub fn is_measuring(&mut self) -> Result<bool, Error<SPIError<SPIE>>> {
+        self.device.is_measuring()
+    }
+
+    /// Reads the result of the triggered conversion, returning `nb::Error::WouldBlock` together
+    /// with `self` while it is still running.
+    /// The sensor returns to sleep mode on its own after a forced conversion.
+    #[allow(clippy::type_complexity)]
+    pub fn read_latest(
+        mut self,
+    ) -> Result<
+        (
+            typestate::BME280<BME280<SPI>, Sleep>,
+            Measurements<SPIError<SPIE>>,
+        ),
+        (Self, nb::Error<Error<SPIError<SPIE>>>),
+    > {
+        match self.device.is_measuring() {
+            Ok(false) => {}
+            Ok(true) => return Err((self, nb::Error::WouldBlock)),
+            Err(e) => return Err((self, nb::Error::Other(e))),
+        }
+        match self.device.common.read_finished() {
+            Ok(measurements) => Ok((self.into_mode(), measurements)),
+            Err(e) => Err((self, nb::Error::Other(e))),
+        }
+    }
+}
+
+impl<SPI, SPIE> typestate::BME280<BME280<SPI>, Normal>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    /// Reads and processes the most recent sample without triggering a new conversion.
+    pub fn read_latest(&mut self) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
+        self.device.read_latest()
+    }
+
+    /// Puts the BME280 into sleep mode.
+    pub fn into_sleep(self) -> Transition<BME280<SPI>, Normal, Sleep, Error<SPIError<SPIE>>> {
+        self.transition(|device| device.sleep())
+    }
+}
+
+impl<SPI, SPIE> traits::Thermometer for BME280<SPI>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    type Error = Error<SPIError<SPIE>>;
+
+    fn temperature(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_temperature(&mut delay)
+    }
+}
+
+impl<SPI, SPIE> traits::Barometer for BME280<SPI>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    type Error = Error<SPIError<SPIE>>;
+
+    fn pressure(&mut self, mut delay: &mut dyn DelayNs) -> Result<f32, Self::Error> {
+        self.measure_pressure(&mut delay)
+    }
+}
+
+impl<SPI, SPIE> traits::Hygrometer for BME280<SPI>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    type Error = Error<SPIError<SPIE>>;
+
+    fn humidity(&mut self, mut delay: &mut dyn DelayNs) -> Result<Option<f32>, Self::Error> {
+        self.measure_humidity(&mut delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<SPI, SPIE, D> embedded_sensors_hal::sensor::ErrorType for sensors::Sensor<BME280<SPI>, D>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    SPIE: core::fmt::Debug,
+{
+    type Error = Error<SPIError<SPIE>>;
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<SPI, SPIE, D> embedded_sensors_hal::temperature::TemperatureSensor
+    for sensors::Sensor<BME280<SPI>, D>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    SPIE: core::fmt::Debug,
+    D: DelayNs,
+{
+    fn temperature(&mut self) -> Result<f32, Self::Error> {
+        self.device.measure_temperature(&mut self.delay)
+    }
+}
+
+#[cfg(feature = "embedded-sensors-hal")]
+impl<SPI, SPIE, D> embedded_sensors_hal::humidity::RelativeHumiditySensor
+    for sensors::Sensor<BME280<SPI>, D>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    SPIE: core::fmt::Debug,
+    D: DelayNs,
+{
+    fn relative_humidity(&mut self) -> Result<f32, Self::Error> {
+        self.device
+            .measure_humidity(&mut self.delay)?
+            .ok_or(Error::InvalidData)
+    }
+}
+
+/// Register access functions for SPI.
+/// This is the [`Interface`] used by [`BME280`], for use with drivers that are generic over the
+/// interface, e.g. to wrap it in [`Retry`](crate::retry::Retry).
 #[derive(Debug, Default)]
-struct SPIInterface<SPI> {
+pub struct SPIInterface<SPI> {
     /// concrete SPI device implementation
     spi: SPI,
+    /// the sensor is wired in 3-wire mode
+    three_wire: bool,
+    /// 3-wire mode has been enabled since the last reset of the sensor
+    three_wire_enabled: bool,
+    /// value last written to the config register
+    config: u8,
+}
+
+impl<SPI> SPIInterface<SPI> {
+    /// Create a new SPIInterface for a sensor wired in 4-wire mode
+    pub fn new(spi: SPI) -> Self {
+        Self::with_wiring(spi, false)
+    }
+
+    /// Create a new SPIInterface for a sensor wired in 3-wire mode, with SDI and SDO shared.
+    /// `spi` needs to perform half-duplex transfers on the shared data line.
+    pub fn new_three_wire(spi: SPI) -> Self {
+        Self::with_wiring(spi, true)
+    }
+
+    fn with_wiring(spi: SPI, three_wire: bool) -> Self {
+        SPIInterface {
+            spi,
+            three_wire,
+            three_wire_enabled: false,
+            config: 0,
+        }
+    }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync = "Interface", async = "AsyncInterface"),
+        SpiDevice(sync = "SpiDevice", async = "AsyncSpiDevice"),
+        read_any_register(sync = "read_any_register", async = "read_any_register_async"),
+        write_any_register(sync = "write_any_register", async = "write_any_register_async")
+    ),
+    sync(keep_self),
+    async(feature = "async", keep_self)
+)]
 impl<SPI> Interface for SPIInterface<SPI>
 where
     SPI: SpiDevice,
-    SPI::Bus: SpiBus,
 {
     type Error = SPIError<SPI::Error>;
 
-    fn read_register(&mut self, register: u8) -> Result<u8, Error<Self::Error>> {
+    async fn read_register(&mut self, register: u8) -> Result<u8, Error<Self::Error>> {
         let mut result = [0u8];
-        self.read_any_register(register, &mut result)?;
+        read_any_register(self, register, &mut result).await?;
         Ok(result[0])
     }
 
-    fn read_data(
+    async fn read_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<Self::Error>> {
         let mut data = [0; BME280_P_T_H_DATA_LEN];
-        self.read_any_register(register, &mut data)?;
+        read_any_register(self, register, &mut data).await?;
         Ok(data)
     }
 
-    fn read_pt_calib_data(
+    async fn read_pt_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<Self::Error>> {
         let mut data = [0; BME280_P_T_CALIB_DATA_LEN];
-        self.read_any_register(register, &mut data)?;
+        read_any_register(self, register, &mut data).await?;
         Ok(data)
     }
 
-    fn read_h_calib_data(
+    async fn read_h_calib_data(
         &mut self,
         register: u8,
     ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<Self::Error>> {
         let mut data = [0; BME280_H_CALIB_DATA_LEN];
-        self.read_any_register(register, &mut data)?;
+        read_any_register(self, register, &mut data).await?;
         Ok(data)
     }
 
-    fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<Self::Error>> {
-        // If the first bit is 0, the register is written.
-        let transfer = [register & 0x7f, payload];
-        self.spi
-            .transfer(&mut [], &transfer)
-            .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+    async fn write_register(
+        &mut self,
+        register: u8,
+        payload: u8,
+    ) -> Result<(), Error<Self::Error>> {
+        // The spi3w_en bit lives in the config register, so it is kept set when the register is
+        // rewritten. A soft reset clears it.
+        let payload = if self.three_wire && register == BME280_CONFIG_ADDR {
+            payload | BME280_SPI3W_EN_MSK
+        } else {
+            payload
+        };
+        write_any_register(&mut self.spi, register, payload).await?;
+        if register == BME280_CONFIG_ADDR {
+            self.config = payload;
+        } else if register == BME280_RESET_ADDR && payload == BME280_SOFT_RESET_CMD {
+            self.three_wire_enabled = false;
+            self.config = 0;
+        }
         Ok(())
     }
 }
 
-impl<SPI> SPIInterface<SPI>
-where
-    SPI: SpiDevice,
-    SPI::Bus: SpiBus,
-{
-    fn read_any_register(
-        &mut self,
-        register: u8,
-        data: &mut [u8],
-    ) -> Result<(), Error<SPIError<SPI::Error>>> {
-        self.spi
-            .transfer(data, &[register])
-            .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
-        Ok(())
+#[maybe_async_cfg::maybe(
+    idents(SpiDevice(sync = "SpiDevice", async = "AsyncSpiDevice")),
+    sync(keep_self),
+    async(feature = "async", self = "write_any_register_async")
+)]
+async fn write_any_register<SPI: SpiDevice>(
+    spi: &mut SPI,
+    register: u8,
+    payload: u8,
+) -> Result<(), Error<SPIError<SPI::Error>>> {
+    // If the first bit is 0, the register is written.
+    let transfer = [register & 0x7f, payload];
+    spi.write(&transfer)
+        .await
+        .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+    Ok(())
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        SpiDevice(sync = "SpiDevice", async = "AsyncSpiDevice"),
+        write_any_register(sync = "write_any_register", async = "write_any_register_async")
+    ),
+    sync(keep_self),
+    async(feature = "async", self = "read_any_register_async")
+)]
+async fn read_any_register<SPI: SpiDevice>(
+    interface: &mut SPIInterface<SPI>,
+    register: u8,
+    data: &mut [u8],
+) -> Result<(), Error<SPIError<SPI::Error>>> {
+    // In 3-wire mode, the sensor only drives the shared data line once spi3w_en is set. Writes
+    // work in either mode, so the bit is set before the first read after a reset. The chip ID
+    // is read first when (re-)initializing, possibly after a power loss the driver did not
+    // notice, so the bit is set again before reading it, keeping the rest of the register.
+    if interface.three_wire && (!interface.three_wire_enabled || register == BME280_CHIP_ID_ADDR) {
+        let config = interface.config | BME280_SPI3W_EN_MSK;
+        write_any_register(&mut interface.spi, BME280_CONFIG_ADDR, config).await?;
+        interface.three_wire_enabled = true;
     }
+    // If the first bit is 1, the register is read. The data is clocked out after the
+    // address byte, so the address write and the data read are separate operations.
+    interface
+        .spi
+        .transaction(&mut [Operation::Write(&[register | 0x80]), Operation::Read(data)])
+        .await
+        .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+    Ok(())
 }
 
 /// Error which occurred during an SPI transaction
@@ -142,3 +859,91 @@ pub enum SPIError<SPIE> {
     /// The SPI implementation returned an error
     SPI(SPIE),
 }
+
+impl<SPIE: core::fmt::Debug> core::fmt::Display for SPIError<SPIE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SPIError::SPI(e) => write!(f, "SPI error: {:?}", e),
+        }
+    }
+}
+
+impl<SPIE: core::fmt::Debug> core::error::Error for SPIError<SPIE> {}
+
+#[cfg(feature = "with_defmt")]
+impl<SPIE> defmt::Format for SPIError<SPIE> {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            SPIError::SPI(_) => defmt::write!(fmt, "SPI error"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+    use embedded_hal::spi::ErrorType;
+
+    /// SPI device recording the register writes and reading zeros
+    #[derive(Default)]
+    struct MockSpi {
+        writes: [[u8; 2]; 8],
+        count: usize,
+    }
+
+    impl ErrorType for MockSpi {
+        type Error = Infallible;
+    }
+
+    impl SpiDevice for MockSpi {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+            for operation in operations {
+                match operation {
+                    Operation::Write(&[register, payload]) => {
+                        self.writes[self.count] = [register, payload];
+                        self.count += 1;
+                    }
+                    Operation::Read(data) => data.fill(0),
+                    _ => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn writes(interface: &SPIInterface<MockSpi>) -> &[[u8; 2]] {
+        &interface.spi.writes[..interface.spi.count]
+    }
+
+    #[test]
+    fn three_wire_mode_is_reasserted() {
+        let mut interface = SPIInterface::new_three_wire(MockSpi::default());
+        interface.read_register(BME280_CHIP_ID_ADDR).unwrap();
+        assert_eq!(writes(&interface), [[0x75, 0x01]]);
+
+        // kept set when the config register is written, and only written once
+        interface.write_register(BME280_CONFIG_ADDR, 0x10).unwrap();
+        interface.read_register(0xF3).unwrap();
+        assert_eq!(writes(&interface)[1..], [[0x75, 0x11]]);
+
+        // set again before the chip ID, keeping the rest of the register
+        interface.read_register(BME280_CHIP_ID_ADDR).unwrap();
+        assert_eq!(writes(&interface)[2..], [[0x75, 0x11]]);
+
+        // a soft reset clears it
+        interface
+            .write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)
+            .unwrap();
+        interface.read_register(0xF3).unwrap();
+        assert_eq!(writes(&interface)[3..], [[0x60, 0xB6], [0x75, 0x01]]);
+    }
+
+    #[test]
+    fn four_wire_mode_leaves_the_config_alone() {
+        let mut interface = SPIInterface::new(MockSpi::default());
+        interface.read_register(BME280_CHIP_ID_ADDR).unwrap();
+        interface.write_register(BME280_CONFIG_ADDR, 0x10).unwrap();
+        assert_eq!(writes(&interface), [[0x75, 0x10]]);
+    }
+}
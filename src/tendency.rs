@@ -0,0 +1,187 @@
+//! Barometric tendency, i.e. the change of the pressure over the last hours.
+//!
+//! ```
+//! use bme280::tendency::{PressureTendency, Trend, THREE_HOURS};
+//!
+//! let mut tendency = PressureTendency::<12>::new(THREE_HOURS);
+//! for minute in 0..=180 {
+//!     tendency.update(101325.0 - 2.0 * minute as f32, minute * 60);
+//! }
+//! assert_eq!(tendency.tendency().unwrap().trend, Trend::Falling);
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Window of the synoptic barometric tendency, in seconds
+pub const THREE_HOURS: u32 = 3 * 60 * 60;
+
+/// Default largest change over the window considered steady, in pascals
+pub const STEADY_THRESHOLD: f32 = 160.0;
+
+/// Direction of the pressure change
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Trend {
+    /// the pressure increases
+    Rising,
+    /// the pressure decreases
+    Falling,
+    /// the pressure changes less than the steady threshold
+    Steady,
+}
+
+/// Pressure change over the window
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tendency {
+    /// direction of the change
+    pub trend: Trend,
+    /// change over the window in pascals, extrapolated from the span of the history
+    pub change: f32,
+}
+
+/// Tracker of the pressure tendency over a window, fed one measurement at a time
+///
+/// The history keeps up to `N` pressures, at least `window / (N - 1)` apart, so that it covers
+/// the whole window whatever the measurement interval. `N` must be at least 2.
+#[derive(Debug, Copy, Clone)]
+pub struct PressureTendency<const N: usize> {
+    window: u32,
+    steady_threshold: f32,
+    history: [(u32, f32); N],
+    len: usize,
+    next: usize,
+    latest: Option<(u32, f32)>,
+}
+
+impl<const N: usize> PressureTendency<N> {
+    /// Create a new tracker for the `window` in seconds, e.g. [`THREE_HOURS`]
+    pub fn new(window: u32) -> Self {
+        PressureTendency {
+            window,
+            steady_threshold: STEADY_THRESHOLD,
+            history: [(0, 0.0); N],
+            len: 0,
+            next: 0,
+            latest: None,
+        }
+    }
+
+    /// Set the largest change over the window in pascals that is considered steady
+    pub fn set_steady_threshold(&mut self, threshold: f32) {
+        self.steady_threshold = threshold;
+    }
+
+    /// Feed the `pressure` in pascals measured at `timestamp`, in seconds from an arbitrary origin
+    /// and allowed to wrap around
+    pub fn update(&mut self, pressure: f32, timestamp: u32) {
+        self.latest = Some((timestamp, pressure));
+        if N < 2 {
+            return;
+        }
+
+        let interval = self.window / (N as u32 - 1);
+        let store = match self.len {
+            0 => true,
+            _ => timestamp.wrapping_sub(self.history[(self.next + N - 1) % N].0) >= interval,
+        };
+        if store {
+            self.history[self.next] = (timestamp, pressure);
+            self.next = (self.next + 1) % N;
+            self.len = (self.len + 1).min(N);
+        }
+    }
+
+    /// Current tendency, `None` until the history covers at least half of the window
+    pub fn tendency(&self) -> Option<Tendency> {
+        let (timestamp, pressure) = self.latest?;
+        if self.len == 0 {
+            return None;
+        }
+        let oldest = if self.len < N { 0 } else { self.next };
+        let (oldest_timestamp, oldest_pressure) = self.history[oldest];
+
+        let span = timestamp.wrapping_sub(oldest_timestamp);
+        if span == 0 || span < self.window / 2 {
+            return None;
+        }
+        let change = (pressure - oldest_pressure) * self.window as f32 / span as f32;
+        let trend = if change.abs() < self.steady_threshold {
+            Trend::Steady
+        } else if change > 0.0 {
+            Trend::Rising
+        } else {
+            Trend::Falling
+        };
+        Some(Tendency { trend, change })
+    }
+
+    /// Latest pressure in pascals, `None` before the first update
+    pub fn pressure(&self) -> Option<f32> {
+        self.latest.map(|(_, pressure)| pressure)
+    }
+
+    /// Forget the history, e.g. after a pause in the measurements
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.next = 0;
+        self.latest = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_samples_an_interval_apart() {
+        // 100 s between the stored samples
+        let mut tendency = PressureTendency::<4>::new(300);
+        for timestamp in (0..=250).step_by(50) {
+            tendency.update(timestamp as f32, timestamp);
+        }
+        assert_eq!(tendency.len, 3);
+        assert_eq!(
+            tendency.history[..3],
+            [(0, 0.0), (100, 100.0), (200, 200.0)]
+        );
+        assert_eq!(tendency.pressure(), Some(250.0));
+    }
+
+    #[test]
+    fn overwrites_the_oldest_sample() {
+        let mut tendency = PressureTendency::<3>::new(200);
+        for timestamp in [0, 100, 200, 300] {
+            tendency.update(100_000.0 + timestamp as f32, timestamp);
+        }
+        assert_eq!(tendency.len, 3);
+        assert_eq!(tendency.history[tendency.next], (100, 100_100.0));
+        let tendency = tendency.tendency().unwrap();
+        assert_eq!(tendency.trend, Trend::Rising);
+        assert_eq!(tendency.change, 200.0);
+    }
+
+    #[test]
+    fn spacing_survives_wrapping_timestamps() {
+        let mut tendency = PressureTendency::<3>::new(200);
+        let start = u32::MAX - 49;
+        for offset in [0, 50, 100, 150] {
+            tendency.update(100_000.0, start.wrapping_add(offset));
+        }
+        assert_eq!(tendency.len, 2);
+        assert_eq!(tendency.history[1].0, 50);
+    }
+
+    #[test]
+    fn needs_half_the_window() {
+        let mut tendency = PressureTendency::<3>::new(200);
+        tendency.update(100_000.0, 0);
+        tendency.update(100_000.0, 99);
+        assert_eq!(tendency.tendency(), None);
+        tendency.update(100_000.0, 100);
+        assert_eq!(tendency.tendency().unwrap().trend, Trend::Steady);
+        tendency.reset();
+        assert_eq!(tendency.tendency(), None);
+    }
+}
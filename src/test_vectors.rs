@@ -0,0 +1,127 @@
+//! Reference vectors for verifying the compensation.
+//!
+//! Each vector holds a set of calibration coefficients, the raw ADC values of a measurement,
+//! and the expected compensated results. The first vector is the worked example of the BMP280
+//! datasheet (section 3.12). The others use calibration data typical of a BME280, with the expected
+//! results computed with the double-precision formulas of the BME280 datasheet (section 8.1).
+//!
+//! ```
+//! use bme280::test_vectors::VECTORS;
+//!
+//! for vector in VECTORS {
+//!     assert!(vector.verify());
+//! }
+//! ```
+
+use super::{compensate, CalibrationData, Measurements, RawData, BME280_CALIB_DATA_LEN};
+
+/// Largest accepted deviation of the temperature, in degrees celsius
+const TEMPERATURE_TOLERANCE: f32 = 0.01;
+/// Largest accepted deviation of the pressure, in pascals
+const PRESSURE_TOLERANCE: f32 = 1.0;
+/// Largest accepted deviation of the humidity, in percent relative humidity
+const HUMIDITY_TOLERANCE: f32 = 0.01;
+
+/// Calibration data of the BMP280 datasheet example
+const BMP280_DATASHEET_CALIBRATION: [u8; BME280_CALIB_DATA_LEN] = [
+    0x70, 0x6B, 0x43, 0x67, 0x18, 0xFC, 0x7D, 0x8E, 0x43, 0xD6, 0xD0, 0x0B, 0x27, 0x0B, 0x8C, 0x00,
+    0xF9, 0xFF, 0x8C, 0x3C, 0xF8, 0xC6, 0x70, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00,
+];
+
+/// Calibration data typical of a BME280
+const BME280_CALIBRATION: [u8; BME280_CALIB_DATA_LEN] = [
+    0x45, 0x6F, 0x6F, 0x68, 0x32, 0x00, 0xAE, 0x99, 0x70, 0xD5, 0xD0, 0x0B, 0xE6, 0x1C, 0x9C, 0xFF,
+    0xF9, 0xFF, 0xAC, 0x26, 0x0A, 0xD8, 0xBD, 0x10, 0x00, 0x4B, 0x6A, 0x01, 0x00, 0x13, 0x29, 0x03,
+    0x1E,
+];
+
+/// Calibration data, raw ADC values, and expected results of a measurement
+#[derive(Debug, Copy, Clone)]
+pub struct Vector {
+    /// calibration data in the layout of [`CalibrationData::to_bytes`]
+    pub calibration: [u8; BME280_CALIB_DATA_LEN],
+    /// raw ADC values
+    pub raw: RawData,
+    /// expected temperature in degrees celsius
+    pub temperature: f32,
+    /// expected pressure in pascals
+    pub pressure: f32,
+    /// expected percent relative humidity, `None` if the humidity is skipped
+    pub humidity: Option<f32>,
+}
+
+impl Vector {
+    /// Compensates the raw ADC values with the calibration data, and checks that the results
+    /// match the expected ones. Channels compiled out with the `humidity` or `pressure`
+    /// features are not checked.
+    pub fn verify(&self) -> bool {
+        let calibration = CalibrationData::from_bytes(&self.calibration);
+        let measurements: Measurements<()> = match compensate(self.raw, &calibration) {
+            Ok(measurements) => measurements,
+            Err(_) => return false,
+        };
+
+        let temperature_ok =
+            (measurements.temperature - self.temperature).abs() <= TEMPERATURE_TOLERANCE;
+        let pressure_ok = !cfg!(feature = "pressure")
+            || (measurements.pressure - self.pressure).abs() <= PRESSURE_TOLERANCE;
+        let humidity_ok = !cfg!(feature = "humidity")
+            || match (measurements.humidity, self.humidity) {
+                (Some(humidity), Some(expected)) => {
+                    (humidity - expected).abs() <= HUMIDITY_TOLERANCE
+                }
+                (None, None) => true,
+                _ => false,
+            };
+        temperature_ok && pressure_ok && humidity_ok
+    }
+}
+
+/// The reference vectors
+pub const VECTORS: &[Vector] = &[
+    Vector {
+        calibration: BMP280_DATASHEET_CALIBRATION,
+        raw: RawData {
+            pressure: 415148,
+            temperature: 519888,
+            humidity: 0x8000,
+        },
+        temperature: 25.08,
+        pressure: 100653.27,
+        humidity: None,
+    },
+    Vector {
+        calibration: BME280_CALIBRATION,
+        raw: RawData {
+            pressure: 339530,
+            temperature: 529100,
+            humidity: 27166,
+        },
+        temperature: 23.38,
+        pressure: 93466.8,
+        humidity: Some(39.20),
+    },
+    Vector {
+        calibration: BME280_CALIBRATION,
+        raw: RawData {
+            pressure: 350200,
+            temperature: 491600,
+            humidity: 32000,
+        },
+        temperature: 11.42,
+        pressure: 89928.35,
+        humidity: Some(65.25),
+    },
+    Vector {
+        calibration: BME280_CALIBRATION,
+        raw: RawData {
+            pressure: 330000,
+            temperature: 560000,
+            humidity: 26000,
+        },
+        temperature: 33.23,
+        pressure: 96567.75,
+        humidity: Some(32.55),
+    },
+];
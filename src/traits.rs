@@ -0,0 +1,34 @@
+//! Object-safe traits over the quantities measured by the sensor.
+//!
+//! Firmware with several sensors can hold them as trait objects, e.g. `&mut dyn Barometer<Error
+//! = E>`, whichever chip and bus each one uses. The delay is passed as a trait object too, so the
+//! methods stay object-safe.
+
+use embedded_hal::delay::DelayNs;
+
+/// Sensor measuring the temperature
+pub trait Thermometer {
+    /// Error type of the sensor
+    type Error;
+
+    /// Measures the temperature in degrees celsius
+    fn temperature(&mut self, delay: &mut dyn DelayNs) -> Result<f32, Self::Error>;
+}
+
+/// Sensor measuring the atmospheric pressure
+pub trait Barometer {
+    /// Error type of the sensor
+    type Error;
+
+    /// Measures the pressure in pascals
+    fn pressure(&mut self, delay: &mut dyn DelayNs) -> Result<f32, Self::Error>;
+}
+
+/// Sensor measuring the relative humidity
+pub trait Hygrometer {
+    /// Error type of the sensor
+    type Error;
+
+    /// Measures the relative humidity in percent, `None` if it is unavailable
+    fn humidity(&mut self, delay: &mut dyn DelayNs) -> Result<Option<f32>, Self::Error>;
+}
@@ -0,0 +1,70 @@
+//! Opt-in typestate API encoding the operating mode of the BME280 in the type system.
+//!
+//! The wrapper is obtained from a bus-specific driver through `into_typestate()`, which puts the
+//! sensor into sleep mode. From there only the operations that are valid in the current mode are
+//! available, e.g. `read_latest()` exists only in normal mode while `trigger()` exists only in
+//! sleep mode.
+//!
+//! The transitions consume the wrapper. If one fails, the wrapper is handed back in its previous
+//! mode together with the error, so that neither the driver nor the bus it owns is lost.
+
+use core::marker::PhantomData;
+
+/// Sleep mode: no conversions are running
+#[derive(Debug, Copy, Clone)]
+pub struct Sleep;
+
+/// Forced mode: a single conversion has been triggered
+#[derive(Debug, Copy, Clone)]
+pub struct Forced;
+
+/// Normal mode: the sensor measures periodically
+#[derive(Debug, Copy, Clone)]
+pub struct Normal;
+
+/// Result of a transition from mode `FROM` to mode `TO`, handing the driver back in mode `FROM`
+/// on failure
+pub type Transition<DEV, FROM, TO, E> = Result<BME280<DEV, TO>, (BME280<DEV, FROM>, E)>;
+
+/// BME280 driver whose operating mode is tracked by the `MODE` type parameter
+#[derive(Debug)]
+pub struct BME280<DEV, MODE> {
+    /// underlying bus-specific driver
+    pub(crate) device: DEV,
+    _mode: PhantomData<MODE>,
+}
+
+impl<DEV> BME280<DEV, Sleep> {
+    pub(crate) fn new(device: DEV) -> Self {
+        BME280 {
+            device,
+            _mode: PhantomData,
+        }
+    }
+}
+
+impl<DEV, MODE> BME280<DEV, MODE> {
+    /// Releases the underlying bus-specific driver.
+    /// The sensor is left in whatever mode it is currently in.
+    pub fn into_inner(self) -> DEV {
+        self.device
+    }
+
+    pub(crate) fn into_mode<NEW>(self) -> BME280<DEV, NEW> {
+        BME280 {
+            device: self.device,
+            _mode: PhantomData,
+        }
+    }
+
+    /// Runs `f` on the device and switches to mode `NEW` if it succeeds
+    pub(crate) fn transition<NEW, E>(
+        mut self,
+        f: impl FnOnce(&mut DEV) -> Result<(), E>,
+    ) -> Transition<DEV, MODE, NEW, E> {
+        match f(&mut self.device) {
+            Ok(()) => Ok(self.into_mode()),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
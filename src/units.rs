@@ -0,0 +1,104 @@
+//! Newtypes tagging values with their unit.
+//!
+//! They are an opt-in alternative to the bare floats of [`Measurements`](super::Measurements),
+//! see e.g. [`Measurements::celsius`](super::Measurements::celsius), so that pascals cannot be
+//! passed where hectopascals are expected.
+//!
+//! ```
+//! use bme280::units::{Celsius, Pascals};
+//!
+//! assert_eq!(Celsius(100.0).to_fahrenheit(), 212.0);
+//! assert_eq!(Pascals::from_hectopascals(1013.25), Pascals(101325.0));
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Temperature in degrees celsius
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Celsius(pub f32);
+
+impl Celsius {
+    /// Temperature from degrees fahrenheit
+    pub fn from_fahrenheit(fahrenheit: f32) -> Self {
+        Celsius((fahrenheit - 32.0) / 1.8)
+    }
+
+    /// Temperature from kelvin
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        Celsius(kelvin - super::derived::ZERO_CELSIUS)
+    }
+
+    /// Temperature in degrees fahrenheit
+    pub fn to_fahrenheit(self) -> f32 {
+        self.0 * 1.8 + 32.0
+    }
+
+    /// Temperature in kelvin
+    pub fn to_kelvin(self) -> f32 {
+        self.0 + super::derived::ZERO_CELSIUS
+    }
+}
+
+impl From<Celsius> for f32 {
+    fn from(temperature: Celsius) -> Self {
+        temperature.0
+    }
+}
+
+/// Pressure in pascals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Pascals(pub f32);
+
+impl Pascals {
+    /// Pressure from hectopascals, i.e. millibars
+    pub fn from_hectopascals(hectopascals: f32) -> Self {
+        Pascals(hectopascals * 100.0)
+    }
+
+    /// Pressure from inches of mercury
+    pub fn from_inches_of_mercury(inches: f32) -> Self {
+        Pascals(inches * 3386.389)
+    }
+
+    /// Pressure in hectopascals, i.e. millibars
+    pub fn to_hectopascals(self) -> f32 {
+        self.0 / 100.0
+    }
+
+    /// Pressure in inches of mercury
+    pub fn to_inches_of_mercury(self) -> f32 {
+        self.0 / 3386.389
+    }
+
+    /// Pressure in millimeters of mercury
+    pub fn to_millimeters_of_mercury(self) -> f32 {
+        self.0 / 133.322_39
+    }
+}
+
+impl From<Pascals> for f32 {
+    fn from(pressure: Pascals) -> Self {
+        pressure.0
+    }
+}
+
+/// Relative humidity in percent
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct RelativeHumidity(pub f32);
+
+impl RelativeHumidity {
+    /// Relative humidity as a fraction from 0 to 1
+    pub fn to_fraction(self) -> f32 {
+        self.0 / 100.0
+    }
+}
+
+impl From<RelativeHumidity> for f32 {
+    fn from(humidity: RelativeHumidity) -> Self {
+        humidity.0
+    }
+}
@@ -0,0 +1,85 @@
+//! Vertical speed from successive pressure measurements.
+//!
+//! ```
+//! use bme280::variometer::Variometer;
+//!
+//! let mut variometer = Variometer::new(1.0);
+//! variometer.update(101325.0, 0);
+//! let vertical_speed = variometer.update(101313.0, 1000);
+//! assert!(vertical_speed > 0.0);
+//! ```
+
+use super::derived;
+
+/// Smoothed climb or sink rate computed from pressures and timestamps
+///
+/// The altitude computed from each pressure and the vertical speed derived from it both pass
+/// through a first-order low-pass filter with the configured time constant, which trades
+/// responsiveness for noise.
+#[derive(Debug, Copy, Clone)]
+pub struct Variometer {
+    time_constant: f32,
+    altitude: f32,
+    vertical_speed: f32,
+    timestamp: Option<u32>,
+}
+
+impl Variometer {
+    /// Create a new variometer, with the `time_constant` of the filters in seconds. A time
+    /// constant of `0.0` disables the filtering.
+    pub fn new(time_constant: f32) -> Self {
+        Variometer {
+            time_constant,
+            altitude: 0.0,
+            vertical_speed: 0.0,
+            timestamp: None,
+        }
+    }
+
+    /// Feed the `pressure` in pascals measured at `timestamp`, in milliseconds from an arbitrary
+    /// origin and allowed to wrap around, and return the vertical speed in meters per second,
+    /// positive when climbing. Samples with the same timestamp as the previous one are ignored.
+    pub fn update(&mut self, pressure: f32, timestamp: u32) -> f32 {
+        let altitude = derived::altitude(pressure, derived::SEA_LEVEL_PRESSURE);
+        let previous = match self.timestamp {
+            Some(previous) => previous,
+            None => {
+                self.altitude = altitude;
+                self.timestamp = Some(timestamp);
+                return self.vertical_speed;
+            }
+        };
+        let dt = timestamp.wrapping_sub(previous) as f32 / 1000.0;
+        if dt <= 0.0 {
+            return self.vertical_speed;
+        }
+
+        let alpha = dt / (self.time_constant + dt);
+        let filtered = self.altitude + alpha * (altitude - self.altitude);
+        let vertical_speed = (filtered - self.altitude) / dt;
+        self.vertical_speed += alpha * (vertical_speed - self.vertical_speed);
+        self.altitude = filtered;
+        self.timestamp = Some(timestamp);
+        self.vertical_speed
+    }
+
+    /// Vertical speed in meters per second as of the last update
+    pub fn vertical_speed(&self) -> f32 {
+        self.vertical_speed
+    }
+
+    /// Filtered altitude in meters above the standard sea-level pressure as of the last update
+    pub fn altitude(&self) -> f32 {
+        self.altitude
+    }
+
+    /// Set the time constant of the filters in seconds
+    pub fn set_time_constant(&mut self, time_constant: f32) {
+        self.time_constant = time_constant;
+    }
+
+    /// Forget the history, e.g. after a pause in the measurements
+    pub fn reset(&mut self) {
+        *self = Variometer::new(self.time_constant);
+    }
+}
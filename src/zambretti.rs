@@ -0,0 +1,133 @@
+//! Local weather forecast after the Zambretti algorithm.
+//!
+//! The forecast is looked up from the pressure reduced to sea level, its tendency over the last
+//! three hours, and the season.
+//!
+//! ```
+//! use bme280::tendency::Trend;
+//! use bme280::zambretti::{Forecast, Hemisphere};
+//!
+//! let forecast = Forecast::new(103500.0, Trend::Steady, 0.0, 7, Hemisphere::Northern);
+//! assert_eq!(forecast.letter(), 'A');
+//! assert_eq!(forecast.description(), "Settled fine");
+//! ```
+
+use super::derived;
+use super::tendency::{PressureTendency, Trend};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+// letters of the forecasts, by increasing Zambretti number for each trend
+const FALLING: &[u8] = b"ABDHORUXZ";
+const STEADY: &[u8] = b"ABEKNPSWXZ";
+const RISING: &[u8] = b"ABCFGIJLMQTYZ";
+
+// descriptions of the forecasts, by letter
+const DESCRIPTIONS: [&str; 26] = [
+    "Settled fine",
+    "Fine weather",
+    "Becoming fine",
+    "Fine, becoming less settled",
+    "Fine, possibly showers",
+    "Fairly fine, improving",
+    "Fairly fine, possibly showers early",
+    "Fairly fine, showery later",
+    "Showery early, improving",
+    "Changeable, mending",
+    "Fairly fine, showers likely",
+    "Rather unsettled, clearing later",
+    "Unsettled, probably improving",
+    "Showery, bright intervals",
+    "Showery, becoming less settled",
+    "Changeable, some rain",
+    "Unsettled, short fine intervals",
+    "Unsettled, rain later",
+    "Unsettled, rain at times",
+    "Very unsettled, finer at times",
+    "Rain at times, worse later",
+    "Rain at times, becoming very unsettled",
+    "Rain at frequent intervals",
+    "Rain, very unsettled",
+    "Stormy, may improve",
+    "Stormy, much rain",
+];
+
+/// Seasonal correction of the pressure, in hectopascals
+const SEASONAL_CORRECTION: f32 = 7.0;
+
+/// Hemisphere of the station, which decides the season of a month
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Hemisphere {
+    /// northern hemisphere, summer from April to September
+    Northern,
+    /// southern hemisphere, summer from October to March
+    Southern,
+}
+
+/// Zambretti forecast, one of the letters `A` (settled fine) to `Z` (stormy, much rain)
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Forecast {
+    letter: u8,
+}
+
+impl Forecast {
+    /// Forecast from the `pressure` in pascals measured at the `altitude` in meters, its `trend`
+    /// over the last three hours, and the `month` from 1 for January to 12 for December
+    pub fn new(
+        pressure: f32,
+        trend: Trend,
+        altitude: f32,
+        month: u8,
+        hemisphere: Hemisphere,
+    ) -> Self {
+        let mut pressure = derived::sea_level_pressure(pressure, altitude) / 100.0;
+
+        let northern_summer = (4..=9).contains(&month);
+        let summer = match hemisphere {
+            Hemisphere::Northern => northern_summer,
+            Hemisphere::Southern => !northern_summer,
+        };
+        match trend {
+            Trend::Rising if summer => pressure += SEASONAL_CORRECTION,
+            Trend::Falling if !summer => pressure -= SEASONAL_CORRECTION,
+            _ => {}
+        }
+
+        // Zambretti numbers run from 1 to 9 when falling, 10 to 19 when steady, and 20 to 32
+        // when rising.
+        let (letters, first, number) = match trend {
+            Trend::Falling => (FALLING, 1, 127.0 - 0.12 * pressure),
+            Trend::Steady => (STEADY, 10, 144.0 - 0.13 * pressure),
+            Trend::Rising => (RISING, 20, 185.0 - 0.16 * pressure),
+        };
+        let index = (libm::roundf(number) as i32 - first).clamp(0, letters.len() as i32 - 1);
+        Forecast {
+            letter: letters[index as usize],
+        }
+    }
+
+    /// Forecast from the latest pressure and the trend of the `tendency` tracker, which should
+    /// cover three hours, `None` while the tendency is unknown
+    pub fn from_tendency<const N: usize>(
+        tendency: &PressureTendency<N>,
+        altitude: f32,
+        month: u8,
+        hemisphere: Hemisphere,
+    ) -> Option<Self> {
+        let trend = tendency.tendency()?.trend;
+        let pressure = tendency.pressure()?;
+        Some(Forecast::new(pressure, trend, altitude, month, hemisphere))
+    }
+
+    /// Letter of the forecast, from `A` to `Z`
+    pub fn letter(&self) -> char {
+        self.letter as char
+    }
+
+    /// Description of the forecast in English
+    pub fn description(&self) -> &'static str {
+        DESCRIPTIONS[(self.letter - b'A') as usize]
+    }
+}